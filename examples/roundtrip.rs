@@ -0,0 +1,30 @@
+//! Checks a vocabulary whose namespace is given by a constant.
+//!
+//! Run the check with `cargo test --example roundtrip`.
+use iref_enum::IriEnum;
+
+const NAMESPACE: &str = "https://schema.org/";
+const KNOWS: &str = const_format::concatcp!(NAMESPACE, "knows");
+
+#[derive(IriEnum, PartialEq, Debug)]
+#[iri_prefix("schema" = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri(expr = KNOWS)]
+	Knows,
+}
+
+pub fn main() {
+	println!("{}", Vocab::Knows.iri());
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn vocab_roundtrip() {
+		Vocab::assert_roundtrip()
+	}
+}
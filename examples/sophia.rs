@@ -0,0 +1,24 @@
+use iref_enum::IriEnum;
+use sophia_api::term::{IriRef, SimpleTerm, Term};
+use sophia_api::triple::Triple;
+
+#[derive(IriEnum, PartialEq, Debug)]
+#[iri(sophia)]
+#[iri_prefix("schema" = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri("schema:knows")]
+	Knows,
+}
+
+pub fn main() {
+	let alice = SimpleTerm::Iri(IriRef::new_unchecked("https://example.org/alice".into()));
+	let bob = SimpleTerm::Iri(IriRef::new_unchecked("https://example.org/bob".into()));
+	let triple = [alice, Vocab::Knows.as_simple(), bob];
+
+	assert_eq!(
+		triple.p().iri().unwrap().as_str(),
+		"https://schema.org/knows"
+	);
+}
@@ -25,9 +25,205 @@
 //! assert_eq!(term, Vocab::Name)
 //! ```
 //!
+//! Since the IRIs are `'static`, the enum also converts into
+//! `&'static Iri` and `&'static IriRef` values that can be stored by value.
+//! With `iref` 3, these references are the borrowed IRI types: there is no
+//! lifetime-parameterized `IriRef<'a>` type anymore.
+//! The `iri` method hence already has value semantics, returning a `Copy`
+//! `&'static Iri`, and no separate by-value getter is generated.
+//! Likewise, `iref` 3 has no IRI type parameterized over its backing storage:
+//! `Iri` is an unsized wrapper around `str`, so IRIs stored in any owner
+//! (`IriBuf`, or a custom type implementing `AsRef<Iri>`) are converted
+//! through `&Iri`, or directly with the generated `from_any` function.
+//!
+//! The IRI of each unit variant is also available as an associated constant,
+//! named after the variant in SCREAMING_SNAKE_CASE, so that IRI strings can
+//! be matched against it:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/givenName")] GivenName
+//! }
+//!
+//! match "https://schema.org/givenName" {
+//!   Vocab::GIVEN_NAME => (),
+//!   _ => unreachable!()
+//! }
+//! ```
+//!
+//! Two variants whose names only differ by case (such as `HttpUrl` and
+//! `HTTPUrl`) hence cannot be declared together, and variants named
+//! `Variants` or `VariantIris` collide with the `VARIANTS` and `VARIANT_IRIS`
+//! constants (see [Const evaluation](#const-evaluation)).
+//! The documentation comments of a variant are copied to its constant (and
+//! to its constants in the namespace types, see
+//! [Compact IRIs](#compact-iris)), so that they show up wherever the
+//! constant is used.
+//!
+//! To keep the generated API minimal, the `#[iri(into = false)]` attribute
+//! only generates the conversions from IRIs: the conversions into `&Iri`,
+//! `&IriRef` or `IriBuf`, the `AsRef` implementations and the methods
+//! returning the IRI of a term (`iri`, `to_compact`, `static_iri`, etc.) are
+//! then left out. The `hash`, `url`, `serde` and `sophia` options, which
+//! rely on those conversions, cannot be used along with it.
+//!
+//! For APIs working with owned IRIs only, the `#[iri(owned)]` attribute
+//! also implements `TryFrom<IriBuf>` for the enum type and `From<Type>` for
+//! `IriBuf` (or `TryFrom<Type>` when some variant captures IRIs), copying the
+//! static IRI of unit variants. The borrowed conversions are still
+//! generated, since the other generated items rely on them.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! # use iref::IriBuf;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(owned)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let iri = IriBuf::new("https://schema.org/name".to_string()).unwrap();
+//! assert_eq!(Vocab::try_from(iri), Ok(Vocab::Name));
+//! assert_eq!(IriBuf::from(Vocab::Name), "https://schema.org/name")
+//! ```
+//!
 //! Each variant must have at most one parameter.
 //! If it has a parameter, its type must implement `TryFrom<&Iri>` and
-//! `AsRef<Iri>`.
+//! `AsRef<Iri>`, and cannot be the enum type itself (even boxed).
+//! Such a type is typically another `IriEnum` type, which may itself wrap
+//! other ones, at any depth. Prefixes are not forwarded across types: each
+//! enum expands its own IRIs, and IRI-to-prefix functions such as `group` or
+//! `to_compact` only use the prefixes of the enum they are called on. Shared
+//! prefix sets (see [Compact IRIs](crate#compact-iris)) avoid repeating the
+//! declarations.
+//! The `#[iri(transparent)]` option excludes such a variant from the
+//! `TryFrom<&Iri>` implementation: it is then only constructed explicitly,
+//! but still converts into an IRI.
+//!
+//! A variant with an `Option<T>` parameter and an `iri` attribute matches
+//! its own IRI with `None`, and any IRI matched by `T` with `Some`.
+//! The exact IRI takes precedence, even if `T` also matches it.
+//!
+//! A variant with a parameter and an `iri` attribute instead captures any IRI
+//! starting with the declared IRI: the remainder (possibly empty) is parsed
+//! into the parameter with `FromStr`. If parsing fails, the next variants are
+//! tried.
+//! Such variants have no `'static` IRI, so the reference conversions are
+//! replaced by a `TryFrom<&Vocab>` implementation for `IriBuf`, using the
+//! `Display` implementation of the parameter.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://ex.org/name")] Name,
+//!   #[iri("https://ex.org/book/")] Book(String)
+//! }
+//!
+//! let term: Vocab = static_iref::iri!("https://ex.org/book/123/page/4").try_into().unwrap();
+//! assert_eq!(term, Vocab::Book("123/page/4".to_string()))
+//! ```
+//!
+//! The `#[iri(default)]` option marks a variant wrapping an `IriBuf` that
+//! captures any IRI not matched by the other variants.
+//! Its IRI is owned by the term, so the reference conversions (and the `iri`
+//! method) borrow the term instead of returning `'static` references.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://ex.org/name")] Name,
+//!   #[iri(default)] Other(iref::IriBuf)
+//! }
+//!
+//! let term: Vocab = static_iref::iri!("https://ex.org/other").try_into().unwrap();
+//! let iri: &iref::Iri = term.as_ref();
+//! assert_eq!(iri, "https://ex.org/other")
+//! ```
+//!
+//! When the fallback wraps another type, the `#[iri(catch)]` option marks
+//! the wrapped variant tried after every other one, whatever its position in
+//! the declaration (wrapped variants are otherwise tried from the last one to
+//! the first). At most one variant can be marked `catch`, and it excludes a
+//! `default` variant.
+//!
+//! A wrapped type accepting non-canonical IRIs (for instance under the
+//! [normalization](#normalization) options) returns its canonical IRI, so
+//! converting the term back does not give the input IRI. The
+//! `#[iri(preserve_original)]` option stores the input IRI in a second
+//! `IriBuf` field of the variant, as given to `TryFrom<&Iri>` (before the
+//! normalizations of the enum), and returns it as the IRI of the term. As
+//! for `default` variants, the reference conversions then borrow the term.
+//! Such variants cannot be matched with the generated `<enum>_match!`
+//! macro, which binds a single field.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(normalize_default_port)]
+//! pub enum Schema {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri(preserve_original)] Schema(Schema, iref::IriBuf)
+//! }
+//!
+//! let term: Vocab = static_iref::iri!("https://schema.org:443/name").try_into().unwrap();
+//! assert_eq!(term.iri(), "https://schema.org:443/name")
+//! ```
+//!
+//! Unrelated to the above, the `#[iri(default_variant)]` option marks the
+//! unit variant returned by a generated `Default` implementation, which is
+//! handy for enums used as struct fields. At most one variant can be marked.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name,
+//!   #[iri("https://schema.org/Thing", default_variant)] Thing
+//! }
+//!
+//! assert_eq!(Vocab::default(), Vocab::Thing)
+//! ```
+//!
+//! For terms accepted depending on a runtime condition (a feature flag, the
+//! environment, etc.), the `#[iri(guard = path::to::function)]` option on a
+//! unit variant takes a `fn() -> bool` function called each time the
+//! variant IRI is matched by `TryFrom<&Iri>`. When it returns `false`, the
+//! IRI falls through to the other variants as if the variant was not
+//! declared (including the wrapped, catch and default variants). Only the
+//! conversions from IRIs (`TryFrom<&Iri>` and the functions built on it,
+//! such as `from_compact`) check guards: `by_local_name`,
+//! `from_iri_const` and the conversions into IRIs ignore them, and the
+//! `inline_data` option cannot be used along with them.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//!
+//! static DRAFT: AtomicBool = AtomicBool::new(false);
+//!
+//! fn draft() -> bool {
+//!   DRAFT.load(Ordering::Relaxed)
+//! }
+//!
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name,
+//!   #[iri("https://schema.org/draftName", guard = draft)] DraftName
+//! }
+//!
+//! let iri = static_iref::iri!("https://schema.org/draftName");
+//! assert_eq!(Vocab::try_from(iri), Err(()));
+//! DRAFT.store(true, Ordering::Relaxed);
+//! assert_eq!(Vocab::try_from(iri), Ok(Vocab::DraftName))
+//! ```
 //!
 //! ## Compact IRIs
 //!
@@ -44,261 +240,5089 @@
 //!   #[iri("schema:knows")] Knows
 //! }
 //! ```
-use iref::IriBuf;
-use proc_macro::TokenStream;
-use proc_macro2::TokenTree;
-use quote::quote;
-use std::collections::HashMap;
+//!
+//! Namespaces whose terms are separated from the base by a fragment, such as
+//! RDF, can either include the `#` in the prefix IRI or give it with the
+//! `sep` option. The separator is then checked to form a valid IRI with
+//! the base:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns", sep = "#")]
+//! pub enum Vocab {
+//!   #[iri("rdf:type")] Type // http://www.w3.org/1999/02/22-rdf-syntax-ns#type
+//! }
+//! ```
+//!
+//! A prefix marked `secondary`, such as a legacy prefix, is only used to read
+//! IRIs: it expands the variant IRIs and compact IRIs given to `from_compact`,
+//! but is never returned by `group`, `matching_prefix` or `to_compact`, nor
+//! listed in the companion prefix enum (see below).
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! #[iri_prefix("legacy" = "https://schema.org/", secondary)]
+//! pub enum Vocab {
+//!   #[iri("legacy:name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::from_compact("legacy:name", &[]), Some(Vocab::Name));
+//! assert_eq!(Vocab::Name.to_compact().as_deref(), Some("schema:name"))
+//! ```
+//!
+//! A prefix can only be declared once. Prefixes are collected before any
+//! variant IRI is expanded, so the order of the declarations does not matter,
+//! except with the `override` flag: a declaration marked
+//! `#[iri_prefix("ex" = "...", override)]` replaces an earlier declaration of
+//! the same prefix, including one from a shared prefix set (see below).
+//!
+//! A prefix only relevant to one variant can be declared on the variant
+//! itself. It is only used to expand the IRI of this variant, taking
+//! precedence over a container prefix with the same name, and is not used
+//! by `group`, `matching_prefix`, `from_compact` or `to_compact`.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name,
+//!   #[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+//!   #[iri("foaf:knows")] Knows
+//! }
+//! ```
+//!
+//! Prefixes can also be read from a Turtle file, relative to the crate root,
+//! with `#[iri_prefix(file = "prefixes.ttl")]`. The file must only contain
+//! `@prefix` (or `PREFIX`) declarations and comments. The default prefix
+//! (`@prefix : <...> .`) is ignored.
+//!
+//! Enums sharing the same prefixes, such as parts of a large vocabulary, can
+//! declare them once with the [`iri_prefixes!`] macro, and refer to the set
+//! with `#[iri_prefix(use = set)]`.
+//!
+//! The prefix IRI can also be read from an environment variable at
+//! compile time, using `env!`:
+//!
+//! ```ignore
+//! #[derive(IriEnum)]
+//! #[iri_prefix("app" = env!("APP_NS"))]
+//! pub enum Vocab {
+//!   #[iri("app:name")] Name
+//! }
+//! ```
+//!
+//! Since IRIs may then collide depending on the build environment, a
+//! `validate` function is generated, checking at runtime that no two unit
+//! variants have the same IRI.
+//!
+//! The `expand = false` option disables the expansion for a given variant:
+//! the value is then taken literally as an absolute IRI, even if it starts
+//! with a declared prefix.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri_prefix("custom" = "https://ex.org/")]
+//! pub enum Vocab {
+//!   #[iri("custom:thing")] Expanded, // https://ex.org/thing
+//!   #[iri("custom:thing", expand = false)] Literal // custom:thing
+//! }
+//! ```
+//!
+//! Since `undefined:name` is itself a valid absolute IRI, a typo in a prefix
+//! name silently produces an unexpected IRI. The `#[iri(require_known_prefix)]`
+//! attribute turns any undeclared prefix into a compile error instead.
+//! Absolute IRIs without `//` after the scheme (such as `urn:...`) must then
+//! be given with `expand = false`.
+//!
+//! Similarly, for consumers accepting only URIs, the `#[iri(ascii_only)]`
+//! attribute turns any non-ASCII character in a variant IRI (after
+//! expansion) into a compile error, so that it gets percent-encoded.
+//!
+//! For protocols capping the length of IRIs, the `#[iri(max_len = 2048)]`
+//! attribute turns any variant IRI (after expansion) longer than the given
+//! number of bytes into a compile error. IRIs given by `expr` are only known
+//! at runtime, and are not checked.
+//!
+//! A variant can also reuse the IRI of a variant declared before it, followed
+//! by a suffix, with the `base_of` and `suffix` options:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri_prefix("ex" = "https://ex.org/")]
+//! pub enum Vocab {
+//!   #[iri("ex:Person")] Person,
+//!   #[iri(base_of = Person, suffix = "/Adult")] Adult // https://ex.org/Person/Adult
+//! }
+//! ```
+//!
+//! A unit variant can also stand for a whole namespace with the
+//! `prefix_only` option. Such a variant never matches in `TryFrom<&Iri>`
+//! (not even its own IRI), but still converts into its IRI, also returned by
+//! the generated `base_iri` method (`None` for other variants):
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/", prefix_only)] Schema,
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::Schema.base_iri(), Some(static_iref::iri!("https://schema.org/")));
+//! assert_eq!(Vocab::try_from(static_iref::iri!("https://schema.org/")), Err(()))
+//! ```
+//!
+//! Enums with prefix-only variants also get a `classify` function, telling
+//! the IRI of a term apart from other IRIs falling under the namespace of a
+//! prefix-only variant, with a companion enum named after the enum type with
+//! a `Classification` suffix:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! # #[derive(IriEnum, PartialEq, Debug)]
+//! # pub enum Vocab {
+//! #   #[iri("https://schema.org/", prefix_only)] Schema,
+//! #   #[iri("https://schema.org/name")] Name
+//! # }
+//! use static_iref::iri;
+//!
+//! assert!(matches!(Vocab::classify(iri!("https://schema.org/name")), VocabClassification::Term(Vocab::Name)));
+//! assert!(matches!(Vocab::classify(iri!("https://schema.org/knows")), VocabClassification::Namespace(Vocab::Schema)));
+//! assert!(matches!(Vocab::classify(iri!("https://ex.org/")), VocabClassification::Unknown))
+//! ```
+//!
+//! The generated `group` method returns the name of the prefix under which
+//! the IRI of a variant falls (longest match), or `""` if none.
+//! Similarly, the `matching_prefix` function returns the prefix under which
+//! any given IRI falls, even if it is not the IRI of a variant, and the
+//! `has_prefix` function checks if a prefix with a given name is declared
+//! (e.g. `Vocab::has_prefix("schema")`).
+//! The declared prefixes are also available as a companion enum, named after
+//! the enum type with a `Prefix` suffix (or given by the
+//! `#[iri(prefix_enum = "...")]` attribute), whose variants are the prefix
+//! names in PascalCase:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name
+//! }
+//!
+//! assert_eq!(VocabPrefix::Schema.as_iri(), static_iref::iri!("https://schema.org/"))
+//! ```
+//!
+//! For large vocabularies spanning several namespaces, the
+//! `#[iri(namespace_enum)]` attribute also generates a companion type per
+//! prefix, named after the enum type and the prefix in PascalCase, whose
+//! associated constants are the unit variants falling under this prefix
+//! (longest match, prefixes with the same IRI being ordered by name). The
+//! constants have the type of the enum, so grouping the variants does not
+//! change the enum itself (inherent associated types are not stable, so they
+//! cannot be nested as `Vocab::Schema::Name`). Wrapped variants, and variants
+//! whose IRI is given by `expr`, have no constant.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(namespace_enum)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! #[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name,
+//!   #[iri("foaf:knows")] Knows
+//! }
+//!
+//! assert_eq!(VocabSchema::Name, Vocab::Name);
+//! assert_eq!(VocabFoaf::Knows, Vocab::Knows)
+//! ```
+//!
+//! To avoid collisions, or to re-export them under a name of your choice,
+//! the `#[iri(prefix_map_name = Name)]` attribute names the prefix enum and
+//! the namespace types after `Name` instead of the enum type (e.g.
+//! `NamePrefix` and `NameSchema`). The `prefix_enum` attribute still takes
+//! precedence for the prefix enum.
+//!
+//! The `to_compact` method goes the other way, returning the `prefix:suffix`
+//! form of the IRI of a variant, and `to_turtle_term` returns its Turtle/N3
+//! form: `schema:name`, or `<https://...>` when no prefix applies (or when
+//! the suffix is not a simple local name). When several prefixes apply, the
+//! one with the longest IRI is used. The
+//! `#[iri(compact_strategy = "...")]` attribute selects another strategy:
+//! `"shortest"` picks the prefix giving the shortest compact IRI, and
+//! `"first_declared"` the first declared one (`"longest_prefix"` is the
+//! default).
+//!
+//! To document or serialize the whole vocabulary in one pass, the `describe`
+//! function iterates over the unit variants with their IRI and compact IRI:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name,
+//!   #[iri("https://ex.org/knows")] Knows
+//! }
+//!
+//! for (term, iri, compact) in Vocab::describe() {
+//!   println!("{term:?}: {iri} ({})", compact.as_deref().unwrap_or("-"))
+//! }
+//! ```
+//!
+//! The opt-in `#[iri(longest_prefix)]` attribute changes the exact-match
+//! semantics of `TryFrom<&Iri>`: when no variant matches an IRI exactly, the
+//! unit variant whose IRI is the longest prefix of it is returned instead,
+//! which suits classifying resources by their namespace. The prefix must
+//! end at a component boundary: either it ends with `/`, `#` or `:`, or it
+//! is followed by one of `/`, `#`, `?` or `:` in the IRI. Hence
+//! `https://ex.org/a` is a prefix of `https://ex.org/a/b`, but not of
+//! `https://ex.org/abc`.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(longest_prefix)]
+//! pub enum Vocab {
+//!   #[iri("https://ex.org/a")] A,
+//!   #[iri("https://ex.org/a/b")] B
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri!("https://ex.org/a/b/c")), Ok(Vocab::B))
+//! ```
+//!
+//! Compact IRIs can also be expanded at runtime, for instance using a JSON-LD
+//! context, with the `from_compact` function. The given context takes
+//! precedence over the declared prefixes:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name
+//! }
+//!
+//! let context = [("s", static_iref::iri!("https://schema.org/"))];
+//! assert_eq!(Vocab::from_compact("s:name", &context), Some(Vocab::Name));
+//! assert_eq!(Vocab::from_compact("schema:name", &context), Some(Vocab::Name))
+//! ```
+//!
+//! ## Namespace
+//!
+//! A namespace IRI can be declared with the `#[iri(namespace = "...")]`
+//! attribute.
+//! A variant IRI that is neither an absolute IRI nor a compact IRI is then
+//! appended to the namespace.
+//! The derive macro also generates a `try_from_suffix` function matching only
+//! the part of the IRI after the namespace, without building the full IRI.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(namespace = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("name")] Name,
+//!   #[iri("knows")] Knows
+//! }
+//!
+//! assert_eq!(Vocab::try_from_suffix("name"), Some(Vocab::Name))
+//! ```
+//!
+//! With a namespace, the `#[iri(rename_all = "...")]` attribute derives the
+//! suffix of unit variants without `iri` attribute from their name, using
+//! one of the `camelCase`, `snake_case`, `kebab-case` or `PascalCase` rules.
+//! An `iri` attribute on a variant overrides the derived suffix.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(namespace = "https://schema.org/", rename_all = "camelCase")]
+//! pub enum Vocab {
+//!   Name,
+//!   DateCreated,
+//!   #[iri("knows")] Friend
+//! }
+//!
+//! assert_eq!(Vocab::try_from_suffix("dateCreated"), Some(Vocab::DateCreated))
+//! ```
+//!
+//! The `#[iri(strict)]` attribute rejects any relative IRI, even when a
+//! namespace is declared, ensuring that the vocabulary only uses absolute
+//! (or compact) IRIs.
+//!
+//! The `#[iri(try_from_iri_ref)]` attribute implements `TryFrom<&IriRef>`,
+//! matching absolute IRI references as `TryFrom<&Iri>` does. Relative
+//! references are resolved against the namespace when one is declared, and
+//! rejected otherwise.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(namespace = "https://schema.org/", try_from_iri_ref)]
+//! pub enum Vocab {
+//!   #[iri("name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri_ref!("https://schema.org/name")), Ok(Vocab::Name));
+//! assert_eq!(Vocab::try_from(static_iref::iri_ref!("name")), Ok(Vocab::Name))
+//! ```
+//!
+//! ## Sophia
+//!
+//! With the `sophia` feature enabled, the `#[iri(sophia)]` attribute
+//! implements the [`sophia_api`](https://docs.rs/sophia_api) `Term` trait
+//! for the enum, so that variants can be used as RDF terms.
+//! The term borrows the `'static` IRI of the variant, without allocation.
+//! The enum type must implement `Debug`, and the `sophia_api` crate must be
+//! in scope.
+//!
+//! ```ignore
+//! #[derive(IriEnum, Debug)]
+//! #[iri(sophia)]
+//! #[iri_prefix("schema" = "https://schema.org/")]
+//! pub enum Vocab {
+//!   #[iri("schema:name")] Name,
+//!   #[iri("schema:knows")] Knows
+//! }
+//!
+//! let triple = [alice, Vocab::Knows.as_simple(), bob];
+//! ```
+//!
+//! The generated inherent `iri` method (returning the `&Iri` of the term)
+//! shadows the `Term::iri` method of `sophia_api` in method-call syntax:
+//! `Vocab::Name.iri()` always calls the inherent method. The trait method
+//! must be called as `Term::iri(&Vocab::Name)`.
+//!
+//! ## URL
+//!
+//! With the `url` feature enabled, the `#[iri(url)]` attribute implements
+//! `TryFrom<&Vocab>` and `TryFrom<Vocab>` for [`url::Url`](https://docs.rs/url),
+//! for HTTP clients using the `url` crate. The `url` crate must be in scope.
+//!
+//! The conversion parses the IRI of the variant following the WHATWG URL
+//! standard, whose rules slightly differ from RFC 3987:
+//! - it can fail, for instance on ports greater than `65535`;
+//! - the resulting URL may be normalized, with non-ASCII characters
+//!   percent-encoded (or punycode-encoded, in the host), lowercase schemes
+//!   and hosts, and default ports removed, so it may not be equal to the IRI.
+//!
+//! ```ignore
+//! #[derive(IriEnum)]
+//! #[iri(url)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let url = url::Url::try_from(Vocab::Name)?;
+//! ```
+//!
+//! ## Serde
+//!
+//! With the `serde` feature enabled, the `#[iri(serde)]` attribute
+//! implements `serde::Serialize` and `serde::Deserialize` for the enum,
+//! (de)serializing each variant as its IRI string. The `serde` crate must be
+//! in scope.
+//!
+//! Variants are serialized with `serialize_str`, never as a struct, so the
+//! enum can be used as a map key:
+//!
+//! ```ignore
+//! #[derive(IriEnum, PartialEq, Eq, Hash)]
+//! #[iri(serde)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let map = HashMap::from([(Vocab::Name, 1)]);
+//! assert_eq!(serde_json::to_string(&map)?, r#"{"https://schema.org/name":1}"#)
+//! ```
+//!
+//! ## Metrics
+//!
+//! With the `metrics` feature enabled, the `TryFrom<&Iri>` conversion counts
+//! the conversions resulting in each unit variant, and the generated
+//! `match_counts` function returns those counts. This helps finding which
+//! terms of a vocabulary are actually used in real data.
+//! Without the feature, no counter is generated.
+//!
+//! ```ignore
+//! for (term, count) in Vocab::match_counts() {
+//!   println!("{}: {}", term.iri(), count)
+//! }
+//! ```
+//!
+//! ## Suggestions
+//!
+//! With the `suggestions` feature enabled, the derive macro also generates a
+//! `closest` function returning the unit variant whose IRI has the smallest
+//! Levenshtein distance to a given IRI, along with that distance.
+//! This is useful to suggest a known term when a conversion fails.
+//!
+//! ```ignore
+//! if let Some((term, 1..=3)) = Vocab::closest(iri) {
+//!   eprintln!("unknown term `{}`, did you mean `{}`?", iri, term.iri())
+//! }
+//! ```
+//!
+//! ## Const evaluation
+//!
+//! For enums with only unit variants, the derive macro also generates a
+//! `from_iri_const` function that can be evaluated in const contexts.
+//! It compares the given string with the IRI of each variant byte per byte.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name,
+//!   #[iri("https://schema.org/knows")] Knows
+//! }
+//!
+//! const NAME: Option<Vocab> = Vocab::from_iri_const("https://schema.org/name");
+//! assert_eq!(NAME, Some(Vocab::Name))
+//! ```
+//!
+//! Such enums may also declare explicit discriminants, exposed by the
+//! generated `as_code` method.
+//! This is useful to map the terms to the codes of a numeric protocol.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name = 1,
+//!   #[iri("https://schema.org/knows")] Knows = 2
+//! }
+//!
+//! assert_eq!(Vocab::Knows.as_code(), 2)
+//! ```
+//!
+//! The `static_iri` method is the const counterpart of `iri`, which can
+//! feed static vocabulary tables. Converting into an owned `IriBuf` allocates,
+//! so it cannot be done in const contexts. `static_iri` is only generated for
+//! enums with only unit variants, none of them using the `expr` option.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! # #[derive(IriEnum)]
+//! # pub enum Vocab {
+//! #   #[iri("https://schema.org/name")] Name,
+//! #   #[iri("https://schema.org/knows")] Knows
+//! # }
+//! const TABLE: [&iref::Iri; 2] = [Vocab::Name.static_iri(), Vocab::Knows.static_iri()];
+//! ```
+//!
+//! The same table is generated as the `VARIANT_IRIS` array, of length
+//! `VARIANTS`, indexed by the position of each term in declaration order as
+//! returned by the `discriminant` method.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! # #[derive(IriEnum)]
+//! # pub enum Vocab {
+//! #   #[iri("https://schema.org/name")] Name,
+//! #   #[iri("https://schema.org/knows")] Knows
+//! # }
+//! const KNOWS: &iref::Iri = Vocab::VARIANT_IRIS[Vocab::Knows.discriminant()];
+//! assert_eq!(Vocab::VARIANTS, 2);
+//! assert_eq!(KNOWS, Vocab::Knows.iri())
+//! ```
+//!
+//! ## Deprecation
+//!
+//! A derive macro cannot attach attributes to the variants of the enum.
+//! Instead, the `#[iri(deprecated = "...")]` variant attribute records a
+//! deprecation note, available at runtime through the generated
+//! `is_deprecated` and `deprecation_note` methods.
+//! To also get compiler warnings when the variant is used, mark it with the
+//! standard `#[deprecated]` attribute: the generated code itself does not
+//! trigger the warning.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/knows")] Knows,
+//!   #[iri("https://schema.org/friend", deprecated = "use `Knows` instead")]
+//!   #[deprecated]
+//!   Friend
+//! }
+//!
+//! assert!(!Vocab::Knows.is_deprecated());
+//! # #[allow(deprecated)]
+//! assert_eq!(Vocab::Friend.deprecation_note(), Some("use `Knows` instead"))
+//! ```
+//!
+//! ## Normalization
+//!
+//! The `#[iri(normalize_default_port)]` attribute makes the `TryFrom<&Iri>`
+//! conversion ignore the default port of the `http` (`80`) and `https` (`443`)
+//! schemes, both in the declared IRIs and in the converted IRI.
+//! Conversions into IRIs still return the IRI as declared.
+//!
+//! With the `unicode` feature enabled, the `#[iri(normalize_unicode)]`
+//! attribute similarly applies the Unicode NFC normalization, so that
+//! canonically equivalent IRIs (e.g. with combining characters) match.
+//! The `unicode-normalization` crate must then be in scope.
+//!
+//! With the `idn` feature enabled, the `#[iri(normalize_idn)]` attribute
+//! converts internationalized host names to their ASCII (punycode) form, so
+//! that `https://café.example/name` matches `https://xn--caf-dma.example/name`.
+//! The `idna` crate must then be in scope. The conversion follows the UTS #46
+//! processing of the `idna` crate, and only applies to hosts with non-ASCII
+//! characters: ASCII hosts, including punycode ones, and percent-encoded
+//! hosts are left unchanged, and hosts rejected by UTS #46 are matched as is.
+//!
+//! The `#[iri(normalize_path)]` attribute removes the dot segments (`.` and
+//! `..`) of the path, as specified by RFC 3986 (section 5.2.4), so that
+//! IRIs built by naive concatenation such as `https://ex.org/a/../b/name`
+//! match `https://ex.org/b/name`.
+//!
+//! The `#[iri(trim_suffix = "/")]` option on a unit variant makes it match
+//! its IRI with or without the given suffix (as if both the declared and
+//! the converted IRIs were compared with the suffix removed once), for
+//! inputs inconsistently using a trailing slash. Other variants still match
+//! strictly, and conversions into IRIs return the IRI as declared. The IRI
+//! with or without the suffix cannot be the declared IRI of another variant.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri("https://ex.org/people/", trim_suffix = "/")] People
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri!("https://ex.org/people")), Ok(Vocab::People));
+//! assert_eq!(Vocab::People.iri(), "https://ex.org/people/")
+//! ```
+//!
+//! By default, the query is part of the match: `https://ex.org/name?lang=en`
+//! does not match `https://ex.org/name`. The `#[iri(match_query = "ignore")]`
+//! attribute removes the query before matching instead (and `"full"` restores
+//! the default).
+//!
+//! The `#[iri(case_insensitive)]` attribute lowercases the whole IRI, path
+//! included, before matching, for sloppy data such as
+//! `https://schema.org/NAME`. This is not conformant: IRIs differing in
+//! case (other than in the scheme and host) are different IRIs, so the
+//! option is opt-in. Conversions into IRIs still return the declared casing.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(normalize_default_port)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri!("https://schema.org:443/name")), Ok(Vocab::Name))
+//! ```
+//!
+//! By default, the `TryFrom<&Iri>` conversion first matches the IRI string
+//! against the declared IRIs, then compares it with each of them as an IRI,
+//! so that equivalent IRIs (e.g. with percent-encoded unreserved characters)
+//! match. The `#[iri(match_str)]` attribute removes the second step: only
+//! IRIs syntactically equal to a declared IRI (after the normalizations
+//! above) match, which makes unknown IRIs much faster to reject.
+//!
+//! The same choice can be spelled out with the `#[iri(compare = "...")]`
+//! attribute: `"normalized"`, the default, keeps both steps, the second one
+//! relying on the `PartialEq` implementation of `iref`'s `Iri` (which
+//! compares the IRI components after decoding percent-encoded characters
+//! and removing dot segments, see its documentation for the exact rules
+//! of the `iref` version in use), while `"raw"` is the same as `match_str`,
+//! comparing the bytes of the IRIs only.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(compare = "raw")]
+//! pub enum Raw {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(compare = "normalized")]
+//! pub enum Normalized {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let iri = static_iref::iri!("https://schema.org/%6Eame");
+//! assert_eq!(Raw::try_from(iri), Err(()));
+//! assert_eq!(Normalized::try_from(iri), Ok(Normalized::Name))
+//! ```
+//!
+//! When the input mostly consists of IRIs unrelated to the vocabulary, the
+//! `#[iri(schemes("https", "http"))]` attribute lists the schemes of the unit
+//! variant IRIs (checked at compile time): IRIs with another scheme then skip
+//! both steps, going straight to the capturing and default variants, if any.
+//! This option is incompatible with the `expr` option.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(schemes("https"))]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri!("ftp://schema.org/name")), Err(()))
+//! ```
+//!
+//! Unless some variant captures IRIs, the generated `normalize` function
+//! returns the declared IRI of the term matching a given IRI, which turns
+//! the non-canonical forms accepted by these rules into the canonical one.
+//! IRIs matching no term are returned as is.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum)]
+//! #[iri(normalize_default_port)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::normalize(static_iref::iri!("https://schema.org:443/name")), "https://schema.org/name")
+//! ```
+//!
+//! For large vocabularies, the `#[iri(inline_data)]` attribute makes both
+//! steps go through a static table of the unit variant IRIs, sorted so that
+//! the first step is a binary search, instead of one match arm per variant
+//! and per step. This shrinks the `TryFrom<&Iri>` implementation. The
+//! `try_from` benchmark (`cargo bench`) compares both strategies on a 500
+//! variants vocabulary, where the binary search does not make the lookup
+//! slower than the match arms. Other generated items, such as `iri`, still
+//! have an arm per variant.
+//!
+//! ## Relative vocabularies
+//!
+//! Document-local vocabularies, such as sections referenced by fragments,
+//! can be declared with IRI references instead of absolute IRIs using the
+//! `#[iri(relative)]` attribute. Every variant must then be a unit variant,
+//! and its IRI reference is taken as is: prefixes are not expanded, and
+//! no namespace can be declared. Only conversions from and into `&IriRef`
+//! are generated, matching IRI references syntactically, along with the
+//! const `iri_ref` method; the other items and options of this crate do not
+//! apply. Conversions to absolute IRIs need a base, given to
+//! `IriRef::resolved`.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(relative)]
+//! pub enum Section {
+//!   #[iri("#introduction")] Introduction,
+//!   #[iri("#usage")] Usage
+//! }
+//!
+//! let usage = iref::IriRef::new("#usage").unwrap();
+//! assert_eq!(Section::try_from(usage), Ok(Section::Usage));
+//! assert_eq!(Section::Introduction.iri_ref().as_str(), "#introduction")
+//! ```
+//!
+//! ## Vocabulary macro
+//!
+//! The `vocabulary!` macro is an alternative to the attribute syntax, declaring
+//! the enum type along with its prefixes and variant IRIs in one place.
+//! It expands to the enum definition with `#[derive(IriEnum)]`, so the
+//! generated items are the same.
+//!
+//! ```rust
+//! iref_enum::vocabulary! {
+//!   pub Vocab {
+//!     "schema" => "https://schema.org/";
+//!     Name => "schema:name";
+//!     Knows => "schema:knows";
+//!   }
+//! }
+//! ```
+//!
+//! Variants can be gated behind cargo features with the
+//! `#[iri(feature = "...")]` shorthand, which becomes a
+//! `#[cfg(feature = "...")]` attribute on the variant. Since the derive macro
+//! only sees the enabled variants, the generated code is always gated the
+//! same way. With the attribute syntax, the derive macro cannot gate the
+//! variant itself: use `#[cfg(feature = "...")]` directly.
+//!
+//! ```rust
+//! iref_enum::vocabulary! {
+//!   pub Vocab {
+//!     Name => "https://schema.org/name";
+//!     #[iri(feature = "extended")]
+//!     Knows => "https://schema.org/knows";
+//!   }
+//! }
+//! ```
+//!
+//! ## Constant IRIs
+//!
+//! The IRI of a unit variant can also be given by a `&'static str` constant,
+//! for instance built with `const_format::concatcp!`, using the `expr` option.
+//! The macro cannot read the constant, so its validity is deferred to runtime:
+//! the conversions into IRIs parse it with `Iri::new`, and panic if it is
+//! invalid.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! const SCHEMA_NAME: &str = "https://schema.org/name";
+//!
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! pub enum Vocab {
+//!   #[iri(expr = SCHEMA_NAME)] Name
+//! }
+//!
+//! assert_eq!(Vocab::try_from(static_iref::iri!("https://schema.org/name")), Ok(Vocab::Name))
+//! ```
+//!
+//! ## Other derives
+//!
+//! The derive macro only reads the `iri` and `iri_prefix` attributes, and does
+//! not implement `FromStr` (unless requested, see [Parsing](#parsing)) or
+//! `Display`, so it can be used along with other derives reading their own attributes, in any order, such as those of
+//! `strum`:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, strum::EnumString, strum::AsRefStr, PartialEq, Debug)]
+//! #[strum(serialize_all = "snake_case")]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/givenName")] GivenName
+//! }
+//!
+//! assert_eq!("given_name".parse(), Ok(Vocab::GivenName))
+//! ```
+//!
+//! Both `IriEnum` and `strum::AsRefStr` implement `AsRef`, so calls to `as_ref`
+//! must then specify the target type (e.g. `AsRef::<iref::Iri>::as_ref(&term)`).
+//!
+//! ## Parsing
+//!
+//! The error type of the `TryFrom<&Iri>` implementation is `()`. With the
+//! `#[iri(borrow_error)]` attribute, it is the input IRI instead
+//! (`type Error = &'a Iri` for an input `&'a Iri`), given back without any
+//! allocation when no variant matches. The error then borrows the input: it
+//! cannot outlive it, and must be turned into an `IriBuf` (with `to_owned`)
+//! to be stored or returned past it. The other conversions (`FromStr`,
+//! `TryFrom<&IriRef>`, etc.) still use their own error types.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(borrow_error)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let iri = static_iref::iri!("https://schema.org/knows");
+//! assert_eq!(Vocab::try_from(iri), Err(iri))
+//! ```
+//!
+//! The `#[iri(from_str)]` attribute implements `FromStr`, parsing the string
+//! as an IRI and matching it as `TryFrom<&Iri>` does.
+//! By default, leading and trailing whitespace is trimmed, which is convenient
+//! when reading IRIs from line-based files. With the
+//! `#[iri(try_from_str_strict)]` attribute instead, `FromStr` is also
+//! implemented but rejects such strings.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(from_str)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(" https://schema.org/name\n".parse(), Ok(Vocab::Name))
+//! ```
+//!
+//! For IRIs read as raw bytes, the `#[iri(from_bytes)]` attribute implements
+//! `TryFrom<&[u8]>`, validating the UTF-8 encoding and the IRI syntax before
+//! matching. Its error type is a companion enum, named after the enum type
+//! with a `FromBytesError` suffix, telling the three failures apart.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(from_bytes)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! assert_eq!(Vocab::try_from(&b"https://schema.org/name"[..]), Ok(Vocab::Name));
+//! assert_eq!(Vocab::try_from(&b"name"[..]), Err(VocabFromBytesError::InvalidIri))
+//! ```
+//!
+//! ## Testing
+//!
+//! In test builds, the derive macro also generates an `assert_roundtrip`
+//! function checking that every unit variant converts into its IRI and back,
+//! and that no two unit variants have the same IRI.
+//! It is especially useful when some IRIs are read from the build environment
+//! or given by constants, since they cannot be checked at compile time.
+//! Since it is gated by `cfg(test)`, it is only available in the unit tests of
+//! the crate declaring the enum:
+//!
+//! ```ignore
+//! #[derive(IriEnum)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! #[test]
+//! fn vocab_roundtrip() {
+//!   Vocab::assert_roundtrip()
+//! }
+//! ```
+//!
+//! Conventions the macro cannot check, such as a mandatory version segment
+//! in every IRI, can be given as a `fn(&str) -> bool` function with the
+//! `#[iri(validate_with = path::to::validator)]` attribute. The macro cannot
+//! call the function while expanding, so the check happens at runtime: the
+//! generated `validate_iris` function runs the validator over the IRI of
+//! every unit variant, and returns the rejected variants (unlike `validate`,
+//! generated for IRIs read from the environment or given by constants,
+//! which checks for collisions). Call it from a test:
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! fn is_versioned(iri: &str) -> bool {
+//!   iri.contains("/v1/")
+//! }
+//!
+//! #[derive(IriEnum, PartialEq, Debug)]
+//! #[iri(validate_with = is_versioned)]
+//! pub enum Vocab {
+//!   #[iri("https://ex.org/v1/name")] Name,
+//!   #[iri("https://ex.org/knows")] Knows
+//! }
+//!
+//! assert_eq!(Vocab::validate_iris(), Err(vec![Vocab::Knows]))
+//! ```
+//!
+//! ## Hashing
+//!
+//! The derive macro does not implement `Hash` nor `Eq`, so they can be derived
+//! as usual, comparing and hashing the variants (and their fields).
+//!
+//! Alternatively, the `#[iri(hash)]` attribute implements `Hash` by hashing the
+//! IRI of the variant, consistently with the `Hash` implementation of `Iri`.
+//! `Hash` must then not be derived, while `PartialEq` and `Eq` still can be,
+//! since equal terms have the same IRI.
+//! This option is incompatible with capturing variants.
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! use std::collections::HashSet;
+//!
+//! #[derive(IriEnum, PartialEq, Eq, Debug)]
+//! #[iri(hash)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name,
+//!   #[iri("https://schema.org/knows")] Knows
+//! }
+//!
+//! let terms: HashSet<Vocab> = [Vocab::Name, Vocab::Knows, Vocab::Name].into_iter().collect();
+//! assert_eq!(terms.len(), 2)
+//! ```
+//!
+//! The `hash` option also implements `Borrow<Iri>`, so that a map keyed by
+//! the enum can be looked up with an `&Iri`. The `Borrow` contract requires
+//! the `Hash` and `Eq` implementations of the enum to agree with the ones of
+//! `Iri`: only derive `PartialEq` and `Eq` (not `Ord`), and make sure that no two
+//! variants have equivalent IRIs (which `assert_roundtrip` checks, see
+//! [Testing](#testing)).
+//!
+//! ```rust
+//! # use iref_enum::IriEnum;
+//! use std::collections::HashMap;
+//!
+//! #[derive(IriEnum, PartialEq, Eq, Debug)]
+//! #[iri(hash)]
+//! pub enum Vocab {
+//!   #[iri("https://schema.org/name")] Name
+//! }
+//!
+//! let map = HashMap::from([(Vocab::Name, 1)]);
+//! assert_eq!(map.get(static_iref::iri!("https://schema.org/name")), Some(&1))
+//! ```
+use iref::{Iri, IriBuf, IriRefBuf};
+use proc_macro::TokenStream;
+use proc_macro2::TokenTree;
+use quote::quote;
+use std::collections::{HashMap, HashSet};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+macro_rules! error {
+	( $( $x:expr ),* ) => {
+		{
+			let msg = format!($($x),*);
+			let tokens: TokenStream = format!("compile_error!(\"{}\");", msg).parse().unwrap();
+			tokens
+		}
+	};
+}
+
+fn filter_attribute(
+	attr: &syn::Attribute,
+	name: &str,
+) -> Result<Option<proc_macro2::TokenStream>, TokenStream> {
+	if let Some(attr_id) = attr.path.get_ident() {
+		if attr_id == name {
+			if let Some(TokenTree::Group(group)) = attr.tokens.clone().into_iter().next() {
+				Ok(Some(group.stream()))
+			} else {
+				Err(error!("malformed `{}` attribute", name))
+			}
+		} else {
+			Ok(None)
+		}
+	} else {
+		Ok(None)
+	}
+}
+
+/// Splits the given value into the prefix and suffix of a compact IRI.
+///
+/// Only the first colon delimits the prefix. Values with an empty prefix or
+/// whose suffix starts with `//` (such as `https://...`) are not compact IRIs.
+fn split_compact_iri(value: &str) -> Option<(&str, &str)> {
+	let (prefix, suffix) = value.split_once(':')?;
+	if prefix.is_empty() || suffix.starts_with("//") {
+		None
+	} else {
+		Some((prefix, suffix))
+	}
+}
+
+/// Invalid IRI produced by the expansion of a compact IRI.
+struct InvalidExpansion {
+	/// Concatenation of the prefix IRI and the suffix.
+	iri: String,
+
+	/// Prefix used for the expansion.
+	prefix: String,
+}
+
+/// Expands the given compact IRI.
+///
+/// Only the first colon delimits the prefix: the rest of the suffix, colons
+/// included, is appended as is to the prefix IRI and never re-expanded.
+/// If the prefix is not declared, `value` is parsed as an absolute IRI.
+///
+/// In particular (see the property tests):
+/// - a declared prefix is always expanded, and an invalid expansion is an
+///   error rather than a fallback to the raw value;
+/// - a prefix declared with an empty name is never used;
+/// - a value whose first colon is followed by `//` is never expanded;
+/// - a value without colon is always an error.
+///
+/// When the expansion of a declared prefix is invalid, the error carries the
+/// concatenated string so it can be reported to the user.
+fn expand_iri(
+	value: &str,
+	prefixes: &HashMap<String, IriBuf>,
+) -> Result<IriBuf, Option<InvalidExpansion>> {
+	if let Some((prefix, suffix)) = split_compact_iri(value) {
+		if let Some(base_iri) = prefixes.get(prefix) {
+			let concat = base_iri.as_str().to_string() + suffix;
+			return IriBuf::new(concat).map_err(|e| {
+				Some(InvalidExpansion {
+					iri: e.0,
+					prefix: prefix.to_owned(),
+				})
+			});
+		}
+	}
+
+	IriBuf::new(value.to_owned()).map_err(|_| None)
+}
+
+/// Returns the value of the given expression if it is a boolean literal.
+fn expr_bool(expr: &syn::Expr) -> Option<bool> {
+	match expr {
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Bool(b),
+			..
+		}) => Some(b.value),
+		_ => None,
+	}
+}
+
+/// Returns the value of the given expression if it is an integer literal
+/// fitting in a `usize`.
+fn expr_usize(expr: &syn::Expr) -> Option<usize> {
+	match expr {
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Int(i),
+			..
+		}) => i.base10_parse().ok(),
+		_ => None,
+	}
+}
+
+/// Evaluated prefix declaration.
+struct PrefixIri {
+	/// IRI of the prefix.
+	iri: String,
+
+	/// The prefix is only used to read IRIs.
+	secondary: bool,
+
+	/// The declaration replaces an earlier declaration of the same prefix.
+	overrides: bool,
+}
+
+/// Documentation of an item generated for a variant, followed by the
+/// documentation of the variant itself, if any.
+fn variant_doc(doc: &str, variant_docs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+	if variant_docs.is_empty() {
+		quote! { #[doc = #doc] }
+	} else {
+		quote! {
+			#[doc = #doc]
+			#[doc = ""]
+			#(#variant_docs)*
+		}
+	}
+}
+
+/// Reads the `iri_prefix` attributes of a variant, only used to expand its
+/// own IRI.
+///
+/// Unlike container prefixes, they cannot be read from a file or a shared
+/// set, nor take the `secondary` and `override` flags.
+fn variant_prefixes(
+	attrs: &[syn::Attribute],
+	env_vars: &mut Vec<String>,
+) -> Result<Vec<(String, IriBuf)>, TokenStream> {
+	let mut prefixes: Vec<(String, IriBuf)> = Vec::new();
+
+	for attr in attrs {
+		if let Some(tokens) = filter_attribute(attr, "iri_prefix")? {
+			let mut tokens = tokens.into_iter();
+			let prefix = match tokens.next().map(string_literal_token) {
+				Some(Ok(prefix)) => prefix,
+				_ => return Err(error!("expected a string literal")),
+			};
+
+			if tokens.next().is_none() {
+				return Err(error!("expected `=` literal"));
+			}
+
+			let PrefixIri {
+				iri,
+				secondary,
+				overrides,
+			} = prefix_iri(tokens.collect(), env_vars)?;
+			if secondary || overrides {
+				return Err(error!(
+					"the `secondary` and `override` flags are not supported on variant prefixes"
+				));
+			}
+
+			if prefixes.iter().any(|(p, _)| *p == prefix) {
+				return Err(error!("prefix `{}` declared more than once", prefix));
+			}
+
+			match IriBuf::new(iri) {
+				Ok(iri) => prefixes.push((prefix, iri)),
+				Err(e) => return Err(error!("invalid IRI `{}` for prefix `{}`", e.0, prefix)),
+			}
+		}
+	}
+
+	Ok(prefixes)
+}
+
+/// Evaluates the IRI of a prefix declaration, given either as a string
+/// literal or as an `env!("VAR")` macro call, optionally followed by a
+/// `, sep = "..."` separator appended to it and the `secondary` and
+/// `override` flags.
+///
+/// The name of every environment variable read is pushed to `env_vars`.
+fn prefix_iri(
+	tokens: proc_macro2::TokenStream,
+	env_vars: &mut Vec<String>,
+) -> Result<PrefixIri, TokenStream> {
+	let mut tokens: Vec<_> = tokens.into_iter().collect();
+	let mut secondary = false;
+	let mut overrides = false;
+	let sep = match tokens
+		.iter()
+		.position(|t| matches!(t, proc_macro2::TokenTree::Punct(p) if p.as_char() == ','))
+	{
+		Some(i) => {
+			let options = tokens.split_off(i).into_iter().skip(1).collect();
+			match syn::parse2::<Args>(options) {
+				Ok(args) => {
+					let mut sep = None;
+					for arg in args.0 {
+						match arg {
+							Arg::Value(id, value) if id == "sep" => match expr_string(&value) {
+								Some(value) if !value.is_empty() => sep = Some(value),
+								_ => return Err(error!("expected a non-empty string literal")),
+							},
+							Arg::Flag(id) if id == "secondary" => secondary = true,
+							Arg::Flag(id) if id == "override" => overrides = true,
+							_ => return Err(error!("unknown `iri_prefix` option")),
+						}
+					}
+					sep
+				}
+				Err(_) => return Err(error!("malformed `iri_prefix` attribute")),
+			}
+		}
+		None => None,
+	};
+
+	let mut iri = prefix_base_iri(tokens.into_iter().collect(), env_vars)?;
+	if let Some(sep) = sep {
+		if iri.ends_with(&sep) {
+			return Err(error!(
+				"prefix IRI `{}` already ends with separator `{}`",
+				iri, sep
+			));
+		}
+
+		iri.push_str(&sep)
+	}
+
+	Ok(PrefixIri {
+		iri,
+		secondary,
+		overrides,
+	})
+}
+
+/// Evaluates the base IRI of a prefix declaration.
+fn prefix_base_iri(
+	tokens: proc_macro2::TokenStream,
+	env_vars: &mut Vec<String>,
+) -> Result<String, TokenStream> {
+	match syn::parse2::<syn::Expr>(tokens) {
+		Ok(syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Str(s),
+			..
+		})) => Ok(s.value()),
+		Ok(syn::Expr::Macro(m)) if m.mac.path.is_ident("env") => {
+			match m.mac.parse_body::<syn::LitStr>() {
+				Ok(var) => {
+					let var = var.value();
+					match std::env::var(&var) {
+						Ok(value) => {
+							env_vars.push(var);
+							Ok(value)
+						}
+						Err(_) => Err(error!("environment variable `{}` is not set", var)),
+					}
+				}
+				Err(_) => Err(error!("expected an environment variable name")),
+			}
+		}
+		_ => Err(error!("expected a string literal")),
+	}
+}
+
+/// Finds the declared prefix whose IRI is the longest prefix of `iri`.
+///
+/// Prefixes with IRIs of the same length are ordered by name, as in the
+/// generated `matching_prefix` function.
+fn longest_prefix<'a>(iri: &str, prefixes: &'a HashMap<String, IriBuf>) -> Option<&'a str> {
+	prefixes
+		.iter()
+		.filter(|(_, base_iri)| iri.starts_with(base_iri.as_str()))
+		.min_by(|(a, a_iri), (b, b_iri)| {
+			b_iri
+				.as_str()
+				.len()
+				.cmp(&a_iri.as_str().len())
+				.then(a.cmp(b))
+		})
+		.map(|(prefix, _)| prefix.as_str())
+}
+
+/// Checks if the given type is the enum type itself (named `type_id` or
+/// `Self`), possibly through a `Box`, `Rc` or `Arc` pointer.
+fn is_self_type(ty: &syn::Type, type_id: &proc_macro2::Ident) -> bool {
+	match ty {
+		syn::Type::Paren(ty) => is_self_type(&ty.elem, type_id),
+		syn::Type::Group(ty) => is_self_type(&ty.elem, type_id),
+		syn::Type::Path(syn::TypePath { qself: None, path }) => match path.segments.last() {
+			Some(segment) if segment.ident == *type_id || segment.ident == "Self" => true,
+			Some(segment) if ["Box", "Rc", "Arc"].iter().any(|p| segment.ident == p) => {
+				match &segment.arguments {
+					syn::PathArguments::AngleBracketed(args) => args.args.iter().any(
+						|arg| matches!(arg, syn::GenericArgument::Type(ty) if is_self_type(ty, type_id)),
+					),
+					_ => false,
+				}
+			}
+			_ => false,
+		},
+		_ => false,
+	}
+}
+
+/// Returns the `T` type of the given `Option<T>` type, if it is one.
+fn option_type(ty: &syn::Type) -> Option<&syn::Type> {
+	match ty {
+		syn::Type::Path(syn::TypePath { qself: None, path }) => {
+			let segment = path.segments.last()?;
+			match &segment.arguments {
+				syn::PathArguments::AngleBracketed(args)
+					if segment.ident == "Option" && args.args.len() == 1 =>
+				{
+					match args.args.first()? {
+						syn::GenericArgument::Type(ty) => Some(ty),
+						_ => None,
+					}
+				}
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+/// Derive options, given by the `iri` attribute on the enum type.
+#[derive(Default)]
+struct Options {
+	/// Implement `sophia_api::term::Term`.
+	sophia: bool,
+
+	/// Implement conversions into `url::Url`.
+	url: bool,
+
+	/// Implement `serde::Serialize` and `serde::Deserialize`.
+	serde: bool,
+
+	/// Implement `Hash` by hashing the IRI of the variant.
+	hash: bool,
+
+	/// Implement `FromStr`.
+	from_str: bool,
+
+	/// Implement the conversions from and into `IriBuf`.
+	owned: bool,
+
+	/// Implement `TryFrom<&IriRef>`.
+	try_from_iri_ref: bool,
+
+	/// Return the input IRI as the error of `TryFrom<&Iri>`.
+	borrow_error: bool,
+
+	/// Reject leading and trailing whitespace in `FromStr`, instead of
+	/// trimming it.
+	from_str_strict: bool,
+
+	/// Implement `TryFrom<&[u8]>`.
+	from_bytes: bool,
+
+	/// Namespace IRI of the vocabulary.
+	namespace: Option<IriBuf>,
+
+	/// Reject relative IRIs, even when a namespace is declared.
+	strict: bool,
+
+	/// Reject declared IRIs with non-ASCII characters.
+	ascii_only: bool,
+
+	/// Reject declared IRIs longer than the given number of bytes.
+	max_len: Option<usize>,
+
+	/// Remove default ports before matching.
+	normalize_default_port: bool,
+
+	/// Apply Unicode NFC normalization before matching.
+	normalize_unicode: bool,
+
+	/// Convert internationalized host names to punycode before matching.
+	normalize_idn: bool,
+
+	/// Remove dot segments from the path before matching.
+	normalize_path: bool,
+
+	/// Remove the query before matching.
+	ignore_query: bool,
+
+	/// Lowercase the whole IRI before matching.
+	case_insensitive: bool,
+
+	/// Only match IRIs syntactically equal to the declared IRIs, without
+	/// the slow path comparing equivalent IRIs (`compare = "raw"`).
+	match_str: bool,
+
+	/// Match unit variants through a sorted table instead of match arms.
+	inline_data: bool,
+
+	/// Only generate the conversions from IRIs (`into = false`).
+	parse_only: bool,
+
+	/// Schemes of the unit variant IRIs, lowercase. Other IRIs skip the
+	/// matching of unit variants.
+	schemes: Option<Vec<String>>,
+
+	/// Reject compact IRIs using an undeclared prefix, instead of parsing
+	/// them as absolute IRIs.
+	require_known_prefix: bool,
+
+	/// Match the variant whose IRI is the longest prefix of the input IRI
+	/// when no variant matches it exactly.
+	longest_prefix: bool,
+
+	/// Rule used to derive the IRI suffix of variants without `iri`
+	/// attribute from their name.
+	rename_all: Option<RenameRule>,
+
+	/// Name of the generated prefix enum.
+	prefix_enum: Option<syn::Ident>,
+
+	/// Name prefixing the generated prefix enum and namespace types, instead
+	/// of the enum name.
+	prefix_map_name: Option<syn::Ident>,
+
+	/// Variants are declared with IRI references, converted from and into
+	/// `IriRef` only.
+	relative: bool,
+
+	/// Generate a companion type per prefix, grouping the unit variants
+	/// falling under it.
+	namespace_enum: bool,
+
+	/// Path to a `fn(&str) -> bool` function validating the variant IRIs.
+	validate_with: Option<syn::Path>,
+
+	/// Prefix selection strategy of `to_compact`.
+	compact_strategy: CompactStrategy,
+}
+
+/// Prefix selection strategy of the generated `to_compact` method, among
+/// the prefixes matching the IRI.
+#[derive(Default, Clone, Copy)]
+enum CompactStrategy {
+	/// Prefix with the longest IRI.
+	#[default]
+	LongestPrefix,
+
+	/// Prefix producing the shortest compact IRI.
+	Shortest,
+
+	/// First declared prefix.
+	FirstDeclared,
+}
+
+impl Options {
+	fn apply(&mut self, arg: Arg) -> Result<(), TokenStream> {
+		match arg {
+			Arg::Flag(id) if id == "sophia" => {
+				if cfg!(feature = "sophia") {
+					self.sophia = true
+				} else {
+					return Err(error!("the `sophia` option requires the `sophia` feature"));
+				}
+			}
+			Arg::Flag(id) if id == "url" => {
+				if cfg!(feature = "url") {
+					self.url = true
+				} else {
+					return Err(error!("the `url` option requires the `url` feature"));
+				}
+			}
+			Arg::Flag(id) if id == "serde" => {
+				if cfg!(feature = "serde") {
+					self.serde = true
+				} else {
+					return Err(error!("the `serde` option requires the `serde` feature"));
+				}
+			}
+			Arg::Flag(id) if id == "hash" => self.hash = true,
+			Arg::Flag(id) if id == "from_str" => self.from_str = true,
+			Arg::Flag(id) if id == "from_bytes" => self.from_bytes = true,
+			Arg::Flag(id) if id == "owned" => self.owned = true,
+			Arg::Flag(id) if id == "try_from_iri_ref" => self.try_from_iri_ref = true,
+			Arg::Flag(id) if id == "borrow_error" => self.borrow_error = true,
+			Arg::Flag(id) if id == "try_from_str_strict" => {
+				self.from_str = true;
+				self.from_str_strict = true
+			}
+			Arg::Flag(id) if id == "relative" => self.relative = true,
+			Arg::Flag(id) if id == "namespace_enum" => self.namespace_enum = true,
+			Arg::Flag(id) if id == "strict" => self.strict = true,
+			Arg::Flag(id) if id == "ascii_only" => self.ascii_only = true,
+			Arg::Flag(id) if id == "normalize_unicode" => {
+				if cfg!(feature = "unicode") {
+					self.normalize_unicode = true
+				} else {
+					return Err(error!(
+						"the `normalize_unicode` option requires the `unicode` feature"
+					));
+				}
+			}
+			Arg::Flag(id) if id == "normalize_idn" => {
+				if cfg!(feature = "idn") {
+					self.normalize_idn = true
+				} else {
+					return Err(error!("the `normalize_idn` option requires the `idn` feature"));
+				}
+			}
+			Arg::Flag(id) if id == "normalize_default_port" => self.normalize_default_port = true,
+			Arg::Flag(id) if id == "case_insensitive" => self.case_insensitive = true,
+			Arg::Flag(id) if id == "normalize_path" => self.normalize_path = true,
+			Arg::Flag(id) if id == "require_known_prefix" => self.require_known_prefix = true,
+			Arg::Flag(id) if id == "longest_prefix" => self.longest_prefix = true,
+			Arg::Flag(id) if id == "match_str" => self.match_str = true,
+			Arg::Flag(id) if id == "inline_data" => self.inline_data = true,
+			Arg::List(id, schemes) if id == "schemes" => {
+				if schemes.is_empty() {
+					return Err(error!("expected at least one scheme"));
+				}
+
+				self.schemes = Some(
+					schemes
+						.iter()
+						.map(|scheme| scheme.value().to_lowercase())
+						.collect(),
+				)
+			}
+			Arg::Value(id, value) if id == "rename_all" => {
+				match expr_string(&value).as_deref().and_then(RenameRule::from_name) {
+					Some(rule) => self.rename_all = Some(rule),
+					None => return Err(error!("unknown renaming rule, expected `camelCase`, `snake_case`, `kebab-case` or `PascalCase`")),
+				}
+			}
+			Arg::Value(id, value) if id == "max_len" => match expr_usize(&value) {
+				Some(max_len) => self.max_len = Some(max_len),
+				None => return Err(error!("expected an integer literal")),
+			},
+			Arg::Value(id, value) if id == "into" => match expr_bool(&value) {
+				Some(b) => self.parse_only = !b,
+				None => return Err(error!("expected a boolean literal")),
+			},
+			Arg::Value(id, value) if id == "match_query" => match expr_string(&value).as_deref() {
+				Some("full") => self.ignore_query = false,
+				Some("ignore") => self.ignore_query = true,
+				_ => {
+					return Err(error!(
+						"unknown query matching mode, expected `full` or `ignore`"
+					))
+				}
+			},
+			Arg::Value(id, value) if id == "compare" => match expr_string(&value).as_deref() {
+				Some("raw") => self.match_str = true,
+				Some("normalized") => self.match_str = false,
+				_ => {
+					return Err(error!(
+						"unknown comparison strategy, expected `raw` or `normalized`"
+					))
+				}
+			},
+			Arg::Value(id, value) if id == "compact_strategy" => {
+				self.compact_strategy = match expr_string(&value).as_deref() {
+					Some("longest_prefix") => CompactStrategy::LongestPrefix,
+					Some("shortest") => CompactStrategy::Shortest,
+					Some("first_declared") => CompactStrategy::FirstDeclared,
+					_ => return Err(error!("unknown compact strategy, expected `longest_prefix`, `shortest` or `first_declared`")),
+				}
+			}
+			Arg::Value(id, value) if id == "validate_with" => match *value {
+				syn::Expr::Path(path) => self.validate_with = Some(path.path),
+				_ => return Err(error!("expected a path to a function")),
+			},
+			Arg::Value(id, value) if id == "prefix_map_name" => match *value {
+				syn::Expr::Path(path) if path.path.get_ident().is_some() => {
+					self.prefix_map_name = path.path.get_ident().cloned()
+				}
+				_ => return Err(error!("expected a type name")),
+			},
+			Arg::Value(id, value) if id == "prefix_enum" => {
+				match expr_string(&value).and_then(|name| syn::parse_str(&name).ok()) {
+					Some(ident) => self.prefix_enum = Some(ident),
+					None => return Err(error!("expected a type name string literal")),
+				}
+			}
+			Arg::Value(id, value) if id == "namespace" => match expr_string(&value) {
+				Some(namespace) => match IriBuf::new(namespace) {
+					Ok(iri) => self.namespace = Some(iri),
+					Err(e) => return Err(error!("invalid namespace IRI `{}`", e.0)),
+				},
+				None => return Err(error!("expected a string literal")),
+			},
+			_ => return Err(error!("unknown `iri` option")),
+		}
+
+		Ok(())
+	}
+
+	/// Normalizes a declared IRI, before matching.
+	fn normalize(&self, iri: &IriBuf) -> IriBuf {
+		let mut iri = iri.clone();
+
+		#[cfg(feature = "idn")]
+		if self.normalize_idn {
+			let host = iri.authority().map(|authority| authority.host().as_str());
+			if let Some(ascii) = host
+				.filter(|host| !host.is_ascii())
+				.and_then(|host| idna::domain_to_ascii(host).ok())
+			{
+				if let (Ok(host), Some(mut authority)) =
+					(iref::iri::Host::new(&ascii), iri.authority_mut())
+				{
+					authority.set_host(host)
+				}
+			}
+		}
+
+		#[cfg(feature = "unicode")]
+		if self.normalize_unicode {
+			use unicode_normalization::UnicodeNormalization;
+			if let Ok(normalized) = IriBuf::new(iri.as_str().nfc().collect()) {
+				iri = normalized
+			}
+		}
+
+		if self.normalize_default_port {
+			let scheme = iri.scheme().as_str().to_ascii_lowercase();
+			if let Some(mut authority) = iri.authority_mut() {
+				if authority
+					.as_authority()
+					.port()
+					.is_some_and(|port| is_default_port(&scheme, port.as_str()))
+				{
+					authority.set_port(None)
+				}
+			}
+		}
+
+		if self.normalize_path {
+			iri.path_mut().normalize()
+		}
+
+		if self.ignore_query {
+			iri.set_query(None)
+		}
+
+		if self.case_insensitive {
+			if let Ok(lowercase) = IriBuf::new(iri.as_str().to_lowercase()) {
+				iri = lowercase
+			}
+		}
+
+		iri
+	}
+
+	/// Generates the statements normalizing the input `iri` of the generated
+	/// `TryFrom<&Iri>` implementation, before matching.
+	///
+	/// Each normalization step is an expression returning the normalized
+	/// `current` IRI, or `None` if it is unchanged, so that no allocation
+	/// happens for IRIs that are already normalized.
+	fn runtime_normalization(&self) -> proc_macro2::TokenStream {
+		let mut steps = Vec::new();
+
+		if self.normalize_idn {
+			steps.push(quote! {
+				match __iref_enum_current.authority().map(|__iref_enum_authority| __iref_enum_authority.host().as_str()) {
+					Some(__iref_enum_host) if !__iref_enum_host.is_ascii() => {
+						::idna::domain_to_ascii(__iref_enum_host).ok().and_then(|__iref_enum_ascii| {
+							let __iref_enum_host = ::iref::iri::Host::new(&__iref_enum_ascii).ok()?;
+							let mut __iref_enum_buffer = __iref_enum_current.to_owned();
+							__iref_enum_buffer.authority_mut()?.set_host(__iref_enum_host);
+							Some(__iref_enum_buffer)
+						})
+					}
+					_ => None,
+				}
+			})
+		}
+
+		if self.normalize_unicode {
+			steps.push(quote! {
+				if ::unicode_normalization::is_nfc(__iref_enum_current.as_str()) {
+					None
+				} else {
+					use ::unicode_normalization::UnicodeNormalization;
+					::iref::IriBuf::new(__iref_enum_current.as_str().nfc().collect()).ok()
+				}
+			})
+		}
+
+		if self.normalize_default_port {
+			steps.push(quote! {
+				match __iref_enum_current.authority().and_then(|__iref_enum_authority| __iref_enum_authority.port()) {
+					Some(__iref_enum_port) if match __iref_enum_port.as_str() {
+						"80" => __iref_enum_current.scheme().as_str().eq_ignore_ascii_case("http"),
+						"443" => __iref_enum_current.scheme().as_str().eq_ignore_ascii_case("https"),
+						_ => false,
+					} => {
+						let mut __iref_enum_buffer = __iref_enum_current.to_owned();
+						if let Some(mut __iref_enum_authority) = __iref_enum_buffer.authority_mut() {
+							__iref_enum_authority.set_port(None)
+						}
+						Some(__iref_enum_buffer)
+					}
+					_ => None,
+				}
+			})
+		}
+
+		if self.normalize_path {
+			steps.push(quote! {
+				if __iref_enum_current.path().segments().any(|__iref_enum_segment| matches!(__iref_enum_segment.as_str(), "." | "..")) {
+					let mut __iref_enum_buffer = __iref_enum_current.to_owned();
+					__iref_enum_buffer.path_mut().normalize();
+					Some(__iref_enum_buffer)
+				} else {
+					None
+				}
+			})
+		}
+
+		if self.ignore_query {
+			steps.push(quote! {
+				__iref_enum_current.query().map(|_| {
+					let mut __iref_enum_buffer = __iref_enum_current.to_owned();
+					__iref_enum_buffer.set_query(None);
+					__iref_enum_buffer
+				})
+			})
+		}
+
+		if self.case_insensitive {
+			steps.push(quote! {
+				if __iref_enum_current.as_str().chars().any(char::is_uppercase) {
+					::iref::IriBuf::new(__iref_enum_current.as_str().to_lowercase()).ok()
+				} else {
+					None
+				}
+			})
+		}
+
+		if steps.is_empty() {
+			proc_macro2::TokenStream::new()
+		} else {
+			quote! {
+				let mut __iref_enum_normalized: Option<::iref::IriBuf> = None;
+				#(
+					let __iref_enum_current: &::iref::Iri = __iref_enum_normalized.as_deref().unwrap_or(__iref_enum_iri);
+					if let Some(__iref_enum_buffer) = #steps {
+						__iref_enum_normalized = Some(__iref_enum_buffer)
+					}
+				)*
+				let __iref_enum_iri: &::iref::Iri = __iref_enum_normalized.as_deref().unwrap_or(__iref_enum_iri);
+			}
+		}
+	}
+}
+
+/// Minimum length of the common prefix of the unit variant IRIs for the
+/// generated `TryFrom<&Iri>` implementation to compare it separately.
+const COMMON_PREFIX_MIN_LEN: usize = 32;
+
+/// Returns the longest common prefix of the given strings.
+fn common_prefix<'a>(strings: &[&'a str]) -> &'a str {
+	let Some((first, rest)) = strings.split_first() else {
+		return "";
+	};
+
+	let mut len = first.len();
+	for s in rest {
+		len = first
+			.char_indices()
+			.zip(s.chars())
+			.take_while(|((i, a), b)| *i < len && a == b)
+			.last()
+			.map_or(0, |((i, a), _)| i + a.len_utf8());
+	}
+
+	&first[..len]
+}
+
+/// Returns the local name of the given IRI: its fragment, or else the last
+/// segment of its path.
+fn local_name(iri: &Iri) -> &str {
+	match iri.fragment() {
+		Some(fragment) => fragment.as_str(),
+		None => iri
+			.path()
+			.segments()
+			.next_back()
+			.map(|segment| segment.as_str())
+			.unwrap_or_default(),
+	}
+}
+
+/// Checks if the given port is the default port of the given (lowercase)
+/// scheme.
+fn is_default_port(scheme: &str, port: &str) -> bool {
+	matches!((scheme, port), ("http", "80") | ("https", "443"))
+}
+
+/// Variant options, given by the `iri` attribute on a variant.
+struct VariantOptions {
+	/// Declared IRI.
+	iri: Option<String>,
+
+	/// Expand the declared IRI if it is a compact IRI.
+	expand: bool,
+
+	/// Deprecation note.
+	deprecated: Option<String>,
+
+	/// Exclude the wrapped variant from the `TryFrom<&Iri>` implementation.
+	transparent: bool,
+
+	/// Capture any IRI not matched by another variant.
+	default: bool,
+
+	/// Try the wrapped type after every other variant.
+	catch: bool,
+
+	/// Store the matched IRI in a second `IriBuf` field, returned by the
+	/// conversions into IRIs instead of the IRI of the wrapped value.
+	preserve_original: bool,
+
+	/// The unit variant stands for a namespace, and is never matched.
+	prefix_only: bool,
+
+	/// The unit variant is returned by the `Default` implementation.
+	default_variant: bool,
+
+	/// Variant whose IRI, followed by `suffix`, is the IRI of this variant.
+	base_of: Option<syn::Ident>,
+
+	/// Suffix appended to the IRI of the `base_of` variant.
+	suffix: Option<String>,
+
+	/// Path to a `&'static str` constant giving the IRI, validated at runtime.
+	expr: Option<syn::Path>,
+
+	/// Path to a `fn() -> bool` function, the unit variant only being matched
+	/// when it returns `true`.
+	guard: Option<syn::Path>,
+
+	/// Suffix ignored at the end of the IRI when matching the unit variant.
+	trim_suffix: Option<String>,
+}
+
+impl Default for VariantOptions {
+	fn default() -> Self {
+		Self {
+			iri: None,
+			expand: true,
+			deprecated: None,
+			transparent: false,
+			default: false,
+			catch: false,
+			preserve_original: false,
+			prefix_only: false,
+			default_variant: false,
+			base_of: None,
+			suffix: None,
+			expr: None,
+			guard: None,
+			trim_suffix: None,
+		}
+	}
+}
+
+impl VariantOptions {
+	/// Collects the options given by the `iri` attributes of a variant.
+	fn from_attributes(attrs: &[syn::Attribute]) -> Result<Self, TokenStream> {
+		let mut options = Self::default();
+		for attr in attrs {
+			if let Some(tokens) = filter_attribute(attr, "iri")? {
+				match syn::parse2::<Args>(tokens) {
+					Ok(args) => {
+						for arg in args.0 {
+							options.apply(arg)?
+						}
+					}
+					Err(_) => return Err(error!("malformed `iri` attribute")),
+				}
+			}
+		}
+
+		Ok(options)
+	}
+
+	fn apply(&mut self, arg: Arg) -> Result<(), TokenStream> {
+		match arg {
+			Arg::Str(value) => self.iri = Some(value.value()),
+			Arg::Value(id, value) if id == "expand" => match expr_bool(&value) {
+				Some(b) => self.expand = b,
+				None => return Err(error!("expected a boolean literal")),
+			},
+			Arg::Value(id, value) if id == "deprecated" => match expr_string(&value) {
+				Some(note) => self.deprecated = Some(note),
+				None => return Err(error!("expected a string literal")),
+			},
+			Arg::Flag(id) if id == "transparent" => self.transparent = true,
+			Arg::Flag(id) if id == "default" => self.default = true,
+			Arg::Flag(id) if id == "catch" => self.catch = true,
+			Arg::Flag(id) if id == "preserve_original" => self.preserve_original = true,
+			Arg::Flag(id) if id == "prefix_only" => self.prefix_only = true,
+			Arg::Value(id, _) if id == "feature" => {
+				return Err(error!(
+					"the `feature` option is only available in `vocabulary!`, gate the variant with `#[cfg(feature = ...)]` instead, the generated code follows it"
+				))
+			}
+			Arg::Flag(id) if id == "default_variant" => self.default_variant = true,
+			Arg::Value(id, value) if id == "base_of" => match *value {
+				syn::Expr::Path(path) if path.path.get_ident().is_some() => {
+					self.base_of = path.path.get_ident().cloned()
+				}
+				_ => return Err(error!("expected a variant name")),
+			},
+			Arg::Value(id, value) if id == "expr" => match *value {
+				syn::Expr::Path(path) => self.expr = Some(path.path),
+				_ => return Err(error!("expected a path to a constant")),
+			},
+			Arg::Value(id, value) if id == "guard" => match *value {
+				syn::Expr::Path(path) => self.guard = Some(path.path),
+				_ => return Err(error!("expected a path to a function")),
+			},
+			Arg::Value(id, value) if id == "suffix" => match expr_string(&value) {
+				Some(suffix) => self.suffix = Some(suffix),
+				None => return Err(error!("expected a string literal")),
+			},
+			Arg::Value(id, value) if id == "trim_suffix" => match expr_string(&value) {
+				Some(suffix) if !suffix.is_empty() => self.trim_suffix = Some(suffix),
+				_ => return Err(error!("expected a non-empty string literal")),
+			},
+			_ => return Err(error!("unknown `iri` option")),
+		}
+
+		Ok(())
+	}
+}
+
+/// Variant renaming rule.
+#[derive(Clone, Copy)]
+enum RenameRule {
+	Camel,
+	Snake,
+	Kebab,
+	Pascal,
+}
+
+impl RenameRule {
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"camelCase" => Some(Self::Camel),
+			"snake_case" => Some(Self::Snake),
+			"kebab-case" => Some(Self::Kebab),
+			"PascalCase" => Some(Self::Pascal),
+			_ => None,
+		}
+	}
+
+	/// Applies the rule to the given (PascalCase) variant name.
+	fn apply(&self, name: &str) -> String {
+		// Split the name into lowercase words.
+		let chars: Vec<char> = name.chars().collect();
+		let mut words: Vec<String> = Vec::new();
+		for (i, &c) in chars.iter().enumerate() {
+			if c == '_' {
+				continue;
+			}
+
+			let starts_word = match i.checked_sub(1).map(|j| chars[j]) {
+				None | Some('_') => true,
+				Some(prev) => {
+					let next_is_lowercase = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+					c.is_uppercase() && (!prev.is_uppercase() || next_is_lowercase)
+				}
+			};
+
+			if starts_word {
+				words.push(String::new())
+			}
+
+			words.last_mut().unwrap().extend(c.to_lowercase())
+		}
+
+		let capitalize = |w: &String| {
+			let mut chars = w.chars();
+			chars
+				.next()
+				.map(|c| c.to_uppercase().chain(chars).collect::<String>())
+				.unwrap_or_default()
+		};
+
+		match self {
+			Self::Camel => words
+				.iter()
+				.enumerate()
+				.map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+				.collect(),
+			Self::Snake => words.join("_"),
+			Self::Kebab => words.join("-"),
+			Self::Pascal => words.iter().map(capitalize).collect(),
+		}
+	}
+}
+
+/// Arguments of an `iri` attribute.
+struct Args(Punctuated<Arg, syn::Token![,]>);
+
+impl Parse for Args {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		Punctuated::parse_terminated(input).map(Self)
+	}
+}
+
+/// Single argument of an `iri` attribute.
+enum Arg {
+	/// String literal, such as the IRI of a variant.
+	Str(syn::LitStr),
+
+	/// Flag option, such as `sophia`.
+	Flag(syn::Ident),
+
+	/// Valued option, such as `namespace = "https://schema.org/"`.
+	Value(syn::Ident, Box<syn::Expr>),
+
+	/// List option, such as `schemes("https", "http")`.
+	List(syn::Ident, Vec<syn::LitStr>),
+}
+
+impl Parse for Arg {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		if input.peek(syn::LitStr) {
+			return input.parse().map(Self::Str);
+		}
+
+		let id = input.call(syn::Ident::parse_any)?;
+		if input.peek(syn::Token![=]) {
+			input.parse::<syn::Token![=]>()?;
+			Ok(Self::Value(id, Box::new(input.parse()?)))
+		} else if input.peek(syn::token::Paren) {
+			let content;
+			syn::parenthesized!(content in input);
+			let items =
+				content.parse_terminated::<_, syn::Token![,]>(<syn::LitStr as Parse>::parse)?;
+			Ok(Self::List(id, items.into_iter().collect()))
+		} else {
+			Ok(Self::Flag(id))
+		}
+	}
+}
+
+/// Returns the value of the given expression if it is a string literal.
+fn expr_string(expr: &syn::Expr) -> Option<String> {
+	match expr {
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Str(s),
+			..
+		}) => Some(s.value()),
+		_ => None,
+	}
+}
+
+/// Derives IRI conversions for an enum type.
+///
+/// Along with the conversion traits, the following items are generated:
+/// - `PartialEq<str>` and `PartialEq<&str>`, comparing the IRI of the
+///   variant with the given string;
+/// - the `iri` method returning the IRI of the variant (`'static` without
+///   default variant), the `iris_of` function mapping a slice of terms to
+///   their IRI strings, and the `to_compact` and `to_turtle_term` methods
+///   (see [Compact IRIs](crate#compact-iris)), unless some variant captures
+///   IRIs;
+/// - the `describe` function, iterating over the unit variants with their
+///   IRI and compact IRI, unless some variant captures IRIs or is the
+///   default variant;
+/// - an associated `&'static str` constant per unit variant, named after the
+///   variant in SCREAMING_SNAKE_CASE (e.g. `Vocab::GIVEN_NAME` for
+///   `GivenName`), giving its IRI;
+/// - the `is_wrapped` method, checking if the variant carries a field;
+/// - the `base_iri` method, returning the IRI of prefix-only variants, and
+///   the `classify` function with its `<enum>Classification` companion enum,
+///   when there are some (see [Compact IRIs](crate#compact-iris));
+/// - the `is_deprecated` and `deprecation_note` methods (see
+///   [Deprecation](crate#deprecation));
+/// - a `<enum>_match!` declarative macro (e.g. `vocab_match!` for `Vocab`),
+///   matching a value of the enum with the compiler checking exhaustiveness.
+///   It is textually scoped, like any `macro_rules!` macro;
+/// - the `parse_all` function, classifying a batch of IRI strings into
+///   matched terms and unmatched strings;
+/// - the `matches_any` function, matching the first of several candidate
+///   IRIs that matches a term;
+/// - the `by_local_name` function, returning the unit variant whose IRI ends
+///   with the given fragment or path segment, unless it is ambiguous;
+/// - the `from_any` function, matching any value implementing `AsRef<Iri>`;
+/// - the `from_iri_cached` function, matching an IRI through a
+///   `HashMap<IriBuf, Self>` cache;
+/// - the `group` method, and the `matching_prefix`, `has_prefix` and
+///   `from_compact` functions (see [Compact IRIs](crate#compact-iris));
+/// - the `from_iri_const` function, the `as_code`, `discriminant` and
+///   `static_iri` methods and the `VARIANTS` and `VARIANT_IRIS` constants,
+///   for unit-only enums (see [Const evaluation](crate#const-evaluation));
+/// - the `try_from_suffix` function, when a namespace is declared (see
+///   [Namespace](crate#namespace));
+/// - the `assert_roundtrip` function, in test builds, and the
+///   `validate_iris` function, with the `validate_with` option (see
+///   [Testing](crate#testing));
+/// - the `match_counts` function, with the `metrics` feature (see
+///   [Metrics](crate#metrics));
+/// - the `closest` function, with the `suggestions` feature, returning the
+///   unit variant whose IRI is the closest to a given IRI;
+/// - a companion `<enum>Prefix` enum listing the declared prefixes, when any,
+///   and a `<enum><Prefix>` type per prefix with the `namespace_enum` option
+///   (see [Compact IRIs](crate#compact-iris)).
+///
+/// The generated items and companion types have the visibility of the enum.
+///
+/// The generated lookup functions returning an `Option`, along with
+/// `parse_all` and `classify`, are `#[must_use]`. Trait methods, such as
+/// `TryFrom::try_from`, and the `validate` functions return a `Result`
+/// which is already `#[must_use]`.
+#[proc_macro_derive(IriEnum, attributes(iri_prefix, iri))]
+pub fn iri_enum_derive(input: TokenStream) -> TokenStream {
+	derive(syn::parse(input).unwrap())
+}
+
+/// Derives `IriEnum` for the given enum type.
+fn derive(mut ast: syn::DeriveInput) -> TokenStream {
+	// A shared prefix set is expanded by its own macro, which derives
+	// `IriEnum` again with the prefixes of the set declared, through
+	// `__iri_enum_derive_with`.
+	for (i, attr) in ast.attrs.iter().enumerate() {
+		if let Ok(Some(tokens)) = filter_attribute(attr, "iri_prefix") {
+			if let Ok(PrefixSetUse(path)) = syn::parse2(tokens) {
+				ast.attrs.remove(i);
+				return quote! {
+					#path! { @iri_enum_derive #ast }
+				}
+				.into();
+			}
+		}
+	}
+
+	let mut prefixes = HashMap::new();
+	let mut env_vars = Vec::new();
+	let mut prefix_files = Vec::new();
+	let mut secondary_prefixes = HashSet::new();
+	// Prefix names, in declaration order (possibly repeated).
+	let mut prefix_order: Vec<String> = Vec::new();
+	let mut options = Options::default();
+	for attr in &ast.attrs {
+		match filter_attribute(attr, "iri") {
+			Ok(Some(tokens)) => match syn::parse2::<Args>(tokens) {
+				Ok(args) => {
+					for arg in args.0 {
+						if let Err(tokens) = options.apply(arg) {
+							return tokens;
+						}
+					}
+				}
+				Err(_) => return error!("malformed `iri` attribute"),
+			},
+			Ok(None) => (),
+			Err(tokens) => return tokens,
+		}
+
+		match filter_attribute(attr, "iri_prefix") {
+			Ok(Some(tokens)) if syn::parse2::<PrefixFile>(tokens.clone()).is_ok() => {
+				let PrefixFile(path) = syn::parse2(tokens).unwrap();
+				let path = path.value();
+				let full_path =
+					std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+						.join(&path);
+				let content = match std::fs::read_to_string(&full_path) {
+					Ok(content) => content,
+					Err(e) => return error!("unable to read prefix file `{}`: {}", path, e),
+				};
+
+				match turtle_prefixes(&content) {
+					Ok(declarations) => {
+						// The default prefix (`:`) cannot be used in compact
+						// IRIs of the enum, nor named in the prefix enum.
+						for (prefix, iri) in declarations {
+							if prefix.is_empty() {
+								continue;
+							}
+
+							match IriBuf::new(iri) {
+								Ok(iri) => {
+									if prefixes.contains_key(&prefix) {
+										return error!(
+											"prefix `{}` declared more than once (in `{}`)",
+											prefix, path
+										);
+									}
+
+									prefix_order.push(prefix.clone());
+									prefixes.insert(prefix, iri);
+								}
+								Err(e) => {
+									return error!(
+										"invalid IRI `{}` for prefix `{}` in `{}`",
+										e.0, prefix, path
+									);
+								}
+							}
+						}
+					}
+					Err((line, message)) => {
+						return error!(
+							"malformed prefix file `{}` (line {}): {}",
+							path, line, message
+						)
+					}
+				}
+
+				prefix_files.push(full_path.to_string_lossy().into_owned());
+			}
+			Ok(Some(tokens)) => {
+				let mut tokens = tokens.into_iter();
+				if let Some(token) = tokens.next() {
+					if let Ok(prefix) = string_literal_token(token) {
+						if tokens.next().is_some() {
+							match prefix_iri(tokens.collect(), &mut env_vars) {
+								Ok(PrefixIri {
+									iri,
+									secondary,
+									overrides,
+								}) => match IriBuf::new(iri) {
+									Ok(iri) => {
+										if prefixes.contains_key(&prefix) && !overrides {
+											return error!(
+												"prefix `{}` declared more than once, use the `override` flag to replace the earlier declaration",
+												prefix
+											);
+										}
+
+										if secondary {
+											secondary_prefixes.insert(prefix.clone());
+										} else {
+											secondary_prefixes.remove(&prefix);
+										}
+
+										prefix_order.push(prefix.clone());
+										prefixes.insert(prefix, iri);
+									}
+									Err(e) => {
+										return error!(
+											"invalid IRI `{}` for prefix `{}`",
+											e.0, prefix
+										);
+									}
+								},
+								Err(tokens) => return tokens,
+							}
+						} else {
+							return error!("expected `=` literal");
+						}
+					} else {
+						return error!("expected a string literal");
+					}
+				} else {
+					return error!("expected a string literal");
+				}
+			}
+			Ok(None) => (),
+			Err(tokens) => return tokens,
+		}
+	}
+
+	// Secondary prefixes are only used to read IRIs.
+	let output_prefixes: HashMap<String, IriBuf> = prefixes
+		.iter()
+		.filter(|(prefix, _)| !secondary_prefixes.contains(*prefix))
+		.map(|(prefix, iri)| (prefix.clone(), iri.clone()))
+		.collect();
+
+	if options.rename_all.is_some() && options.namespace.is_none() {
+		return error!("the `rename_all` option requires a namespace");
+	}
+
+	if options.parse_only {
+		for (name, enabled) in [
+			("hash", options.hash),
+			("url", options.url),
+			("serde", options.serde),
+			("sophia", options.sophia),
+		] {
+			if enabled {
+				return error!("the `{}` option is incompatible with `into = false`", name);
+			}
+		}
+	}
+
+	match ast.data {
+		syn::Data::Enum(e) if options.relative => {
+			if options.namespace.is_some() {
+				return error!("the `relative` option is incompatible with namespaces");
+			}
+
+			if options.borrow_error {
+				return error!(
+					"the `borrow_error` option is incompatible with the `relative` option"
+				);
+			}
+
+			if options.try_from_iri_ref {
+				// Relative vocabularies always implement `TryFrom<&IriRef>`.
+				return error!(
+					"the `try_from_iri_ref` option is incompatible with the `relative` option"
+				);
+			}
+
+			derive_relative(&ast.ident, &ast.vis, &e)
+		}
+		syn::Data::Enum(e) => {
+			let type_id = ast.ident;
+			// Generated items are as visible as the enum.
+			let vis = &ast.vis;
+			let mut unit_arms: Vec<(String, proc_macro2::TokenStream, Option<syn::Path>)> =
+				Vec::new();
+			// Additional arms given by the `trim_suffix` option, with the
+			// variant.
+			let mut trimmed_arms: Vec<(
+				String,
+				proc_macro2::TokenStream,
+				Option<syn::Path>,
+				syn::Ident,
+			)> = Vec::new();
+			let mut declared_unit_iris: Vec<(
+				String,
+				proc_macro2::TokenStream,
+				proc_macro2::TokenStream,
+			)> = Vec::new();
+			let mut expr_arms = proc_macro2::TokenStream::new();
+			let mut expr_equivalent_arms = proc_macro2::TokenStream::new();
+			let mut has_expr = false;
+			let mut delegations = Vec::new();
+			let mut variant_iris: HashMap<String, IriBuf> = HashMap::new();
+			let mut default_variant = None;
+			let mut catch_variant = None;
+			// Unit variants with a literal IRI, under their prefix.
+			let mut namespace_members: Vec<(&str, syn::Ident)> = Vec::new();
+			// Local name of each unit variant with a literal IRI.
+			let mut local_names: Vec<(String, syn::Ident)> = Vec::new();
+			let mut default_unit_variant = None;
+			let mut into = proc_macro2::TokenStream::new();
+			let mut group = proc_macro2::TokenStream::new();
+			let mut try_from_suffix = proc_macro2::TokenStream::new();
+			let mut captures = proc_macro2::TokenStream::new();
+			let mut owned_into = proc_macro2::TokenStream::new();
+			let mut has_capture = false;
+			let mut has_default = false;
+			let mut has_original = false;
+			let mut unit_only = true;
+			let mut from_iri_const = proc_macro2::TokenStream::new();
+			let mut variant_idents = Vec::new();
+			let mut str_eq = proc_macro2::TokenStream::new();
+			let mut deprecated = proc_macro2::TokenStream::new();
+			let mut wrapped = proc_macro2::TokenStream::new();
+			let mut base_iri = proc_macro2::TokenStream::new();
+			let mut namespace_variants: Vec<(String, syn::Ident)> = Vec::new();
+			let mut iri_consts: Vec<(String, proc_macro2::TokenStream, syn::Ident)> = Vec::new();
+			// Documentation of each variant, copied to the generated constants.
+			let mut variant_docs: HashMap<String, Vec<syn::Attribute>> = HashMap::new();
+
+			// Prefixes sorted by decreasing IRI length, so that the first match
+			// found at runtime is the longest.
+			let mut sorted_prefixes: Vec<_> = prefixes.iter().collect();
+			sorted_prefixes.sort_by(|(a, a_iri), (b, b_iri)| {
+				b_iri
+					.as_str()
+					.len()
+					.cmp(&a_iri.as_str().len())
+					.then(a.cmp(b))
+			});
+			let sorted_prefix_names: Vec<_> = sorted_prefixes
+				.iter()
+				.map(|(prefix, _)| prefix.as_str())
+				.collect();
+			let sorted_prefix_iris: Vec<_> = sorted_prefixes
+				.iter()
+				.map(|(_, iri)| iri.as_str())
+				.collect();
+			let (output_prefix_names, output_prefix_iris): (Vec<_>, Vec<_>) = sorted_prefixes
+				.iter()
+				.filter(|(prefix, _)| !secondary_prefixes.contains(*prefix))
+				.map(|(prefix, iri)| (prefix.as_str(), iri.as_str()))
+				.unzip();
+
+			// Prefixes tried by `to_compact`, in order.
+			let mut compact_prefixes: Vec<(&str, &str)> = output_prefix_names
+				.iter()
+				.copied()
+				.zip(output_prefix_iris.iter().copied())
+				.collect();
+			match options.compact_strategy {
+				CompactStrategy::LongestPrefix => (),
+				CompactStrategy::Shortest => compact_prefixes.sort_by_key(|(name, iri)| {
+					(
+						name.len() as isize - iri.len() as isize,
+						std::cmp::Reverse(iri.len()),
+					)
+				}),
+				CompactStrategy::FirstDeclared => compact_prefixes
+					.sort_by_key(|(name, _)| prefix_order.iter().position(|prefix| prefix == name)),
+			}
+			let (compact_prefix_names, compact_prefix_iris): (Vec<_>, Vec<_>) =
+				compact_prefixes.into_iter().unzip();
+
+			for variant in e.variants {
+				let variant_ident = variant.ident;
+				let mut variant_iri: Option<IriBuf> = None;
+				variant_idents.push(variant_ident.clone());
+				variant_docs.insert(
+					variant_ident.to_string(),
+					variant
+						.attrs
+						.iter()
+						.filter(|attr| attr.path.is_ident("doc"))
+						.cloned()
+						.collect(),
+				);
+
+				let variant_options = match VariantOptions::from_attributes(&variant.attrs) {
+					Ok(variant_options) => variant_options,
+					Err(tokens) => return tokens,
+				};
+
+				if !matches!(variant.fields, syn::Fields::Unit) {
+					wrapped.extend(quote! {
+						#type_id::#variant_ident { .. } => true,
+					});
+				}
+
+				if let Some(note) = &variant_options.deprecated {
+					deprecated.extend(quote! {
+						#type_id::#variant_ident { .. } => Some(#note),
+					});
+				}
+
+				if variant_options.expr.is_some()
+					&& (variant_options.iri.is_some()
+						|| variant_options.base_of.is_some()
+						|| !matches!(variant.fields, syn::Fields::Unit))
+				{
+					return error!(
+						"the `expr` option of variant `{}` is only supported on unit variants without other IRI",
+						variant_ident
+					);
+				}
+
+				match (&variant_options.base_of, &variant_options.suffix) {
+					(Some(base_variant), Some(suffix)) => {
+						if variant_options.iri.is_some() {
+							return error!(
+								"variant `{}` has both an IRI and a `base_of` option",
+								variant_ident
+							);
+						}
+
+						match variant_iris.get(&base_variant.to_string()) {
+							Some(base) => match IriBuf::new(base.as_str().to_owned() + suffix) {
+								Ok(iri) => variant_iri = Some(iri),
+								Err(e) => {
+									return error!(
+										"invalid IRI `{}` for variant `{}`",
+										e.0, variant_ident
+									)
+								}
+							},
+							None => {
+								return error!(
+									"variant `{}` referenced by `{}` must be declared before it, with an IRI",
+									base_variant, variant_ident
+								)
+							}
+						}
+					}
+					(None, None) => (),
+					_ => {
+						return error!(
+							"the `base_of` and `suffix` options of variant `{}` go together",
+							variant_ident
+						)
+					}
+				}
+
+				let local_prefixes = match variant_prefixes(&variant.attrs, &mut env_vars) {
+					Ok(local_prefixes) => local_prefixes,
+					Err(tokens) => return tokens,
+				};
+
+				// Variant prefixes take precedence over the container ones.
+				let variant_prefixes = if local_prefixes.is_empty() {
+					std::borrow::Cow::Borrowed(&prefixes)
+				} else {
+					let mut variant_prefixes = prefixes.clone();
+					variant_prefixes.extend(local_prefixes);
+					std::borrow::Cow::Owned(variant_prefixes)
+				};
+
+				if let Some(str) = variant_options.iri {
+					if variant_options.expand && options.require_known_prefix {
+						if let Some((prefix, _)) = split_compact_iri(&str) {
+							if !variant_prefixes.contains_key(prefix) {
+								return error!(
+									"undeclared prefix `{}` for variant `{}`",
+									prefix, variant_ident
+								);
+							}
+						}
+					}
+
+					let iri = match (variant_options.expand, &options.namespace) {
+						(false, _) => IriBuf::new(str.clone()).map_err(|_| None),
+						(true, namespace) => match (expand_iri(&str, &variant_prefixes), namespace)
+						{
+							(Err(None), Some(namespace)) if !options.strict => {
+								IriBuf::new(namespace.as_str().to_owned() + &str).map_err(|_| None)
+							}
+							(result, _) => result,
+						},
+					};
+
+					match iri {
+						Ok(iri) => variant_iri = Some(iri),
+						Err(Some(expansion)) => {
+							return error!(
+								"invalid IRI `{}` (from `{}` with prefix `{}`) for variant `{}`",
+								expansion.iri, str, expansion.prefix, variant_ident
+							);
+						}
+						Err(None) if options.strict && IriRefBuf::new(str.clone()).is_ok() => {
+							return error!(
+								"relative IRI `{}` for variant `{}` (strict mode)",
+								str, variant_ident
+							);
+						}
+						Err(None) => {
+							return error!("invalid IRI `{}` for variant `{}`", str, variant_ident);
+						}
+					}
+				}
+
+				if let (None, None, syn::Fields::Unit, Some(rule), Some(namespace)) = (
+					&variant_iri,
+					&variant_options.expr,
+					&variant.fields,
+					options.rename_all,
+					&options.namespace,
+				) {
+					let suffix = rule.apply(&variant_ident.to_string());
+					match IriBuf::new(namespace.as_str().to_owned() + &suffix) {
+						Ok(iri) => variant_iri = Some(iri),
+						Err(e) => {
+							return error!("invalid IRI `{}` for variant `{}`", e.0, variant_ident)
+						}
+					}
+				}
+
+				if let Some(iri) = &variant_iri {
+					if options.ascii_only {
+						if let Some(c) = iri.as_str().chars().find(|c| !c.is_ascii()) {
+							return error!(
+								"non-ASCII character `{}` in IRI `{}` of variant `{}`, percent-encode it (ASCII-only mode)",
+								c, iri, variant_ident
+							);
+						}
+					}
+
+					if let Some(max_len) = options.max_len {
+						if iri.as_str().len() > max_len {
+							return error!(
+								"IRI `{}` of variant `{}` is {} bytes long, exceeding the maximum length of {} bytes",
+								iri,
+								variant_ident,
+								iri.as_str().len(),
+								max_len
+							);
+						}
+					}
+
+					variant_iris.insert(variant_ident.to_string(), iri.clone());
+				}
+
+				for (option, set) in [
+					("transparent", variant_options.transparent),
+					("default", variant_options.default),
+					("catch", variant_options.catch),
+					("preserve_original", variant_options.preserve_original),
+				] {
+					if set && (variant_iri.is_some() || matches!(variant.fields, syn::Fields::Unit))
+					{
+						return error!(
+							"`{}` option on variant `{}` without wrapped type",
+							option, variant_ident
+						);
+					}
+				}
+
+				if variant_options.prefix_only
+					&& (variant_iri.is_none() || !matches!(variant.fields, syn::Fields::Unit))
+				{
+					return error!(
+						"`prefix_only` option on variant `{}` without IRI, or with a wrapped type",
+						variant_ident
+					);
+				}
+
+				for (option, set) in [
+					("guard", variant_options.guard.is_some()),
+					("trim_suffix", variant_options.trim_suffix.is_some()),
+				] {
+					if set
+						&& (variant_iri.is_none()
+							|| variant_options.prefix_only
+							|| !matches!(variant.fields, syn::Fields::Unit))
+					{
+						return error!(
+							"`{}` option on variant `{}` without IRI, or with a wrapped type",
+							option, variant_ident
+						);
+					}
+				}
+
+				if variant_options.default_variant {
+					if !matches!(variant.fields, syn::Fields::Unit) {
+						return error!(
+							"`default_variant` option on variant `{}` with a wrapped type",
+							variant_ident
+						);
+					}
+
+					if default_unit_variant.is_some() {
+						return error!("multiple default_variant markers");
+					}
+
+					default_unit_variant = Some(variant_ident.clone());
+				}
+
+				match variant.fields {
+					syn::Fields::Unit => {
+						if let Some(iri) = variant_iri {
+							let normalized_iri = options.normalize(&iri);
+							let normalized_iri = normalized_iri.as_str();
+							let local_name = local_name(&iri).to_owned();
+							let iri = iri.as_str();
+							let prefix = longest_prefix(iri, &output_prefixes).unwrap_or_default();
+							iri_consts.push((
+								RenameRule::Snake
+									.apply(&variant_ident.to_string())
+									.to_uppercase(),
+								quote! { #iri },
+								variant_ident.clone(),
+							));
+
+							if !prefix.is_empty() {
+								namespace_members.push((prefix, variant_ident.clone()));
+							}
+
+							if variant_options.prefix_only {
+								// The variant is excluded from matching.
+								namespace_variants.push((iri.to_owned(), variant_ident.clone()));
+								base_iri.extend(quote! {
+									#type_id::#variant_ident => Some(::iref_enum::iri!(#iri)),
+								});
+
+								into.extend(quote! {
+									#type_id::#variant_ident => ::iref_enum::iri!(#iri),
+								});
+
+								owned_into.extend(quote! {
+									#type_id::#variant_ident => Ok(::iref_enum::iri!(#iri).to_owned()),
+								});
+
+								str_eq.extend(quote! {
+									#type_id::#variant_ident => __iref_enum_other == #iri,
+								});
+
+								group.extend(quote! {
+									#type_id::#variant_ident => #prefix,
+								});
+
+								continue;
+							}
+
+							unit_arms.push((
+								normalized_iri.to_owned(),
+								quote! { #type_id::#variant_ident },
+								variant_options.guard.clone(),
+							));
+
+							// The variant also matches its IRI with the suffix
+							// removed, or added.
+							if let Some(suffix) = &variant_options.trim_suffix {
+								let other_iri = match iri.strip_suffix(suffix.as_str()) {
+									Some(trimmed) => trimmed.to_owned(),
+									None => iri.to_owned() + suffix,
+								};
+
+								match IriBuf::new(other_iri) {
+									Ok(other_iri) => trimmed_arms.push((
+										options.normalize(&other_iri).into_string(),
+										quote! { #type_id::#variant_ident },
+										variant_options.guard.clone(),
+										variant_ident.clone(),
+									)),
+									Err(e) => {
+										return error!(
+											"invalid IRI `{}` for variant `{}` (with `trim_suffix`)",
+											e.0, variant_ident
+										)
+									}
+								}
+							}
+							local_names.push((local_name, variant_ident.clone()));
+							declared_unit_iris.push((
+								variant_ident.to_string(),
+								quote! { #normalized_iri },
+								quote! { #type_id::#variant_ident },
+							));
+
+							into.extend(quote! {
+								#type_id::#variant_ident => ::iref_enum::iri!(#iri),
+							});
+
+							owned_into.extend(quote! {
+								#type_id::#variant_ident => Ok(::iref_enum::iri!(#iri).to_owned()),
+							});
+
+							str_eq.extend(quote! {
+								#type_id::#variant_ident => __iref_enum_other == #iri,
+							});
+
+							from_iri_const.extend(quote! {
+								if __iref_enum_eq(__iref_enum_s, #iri.as_bytes()) {
+									return Some(#type_id::#variant_ident);
+								}
+							});
+
+							group.extend(quote! {
+								#type_id::#variant_ident => #prefix,
+							});
+
+							if let Some(suffix) = options
+								.namespace
+								.as_ref()
+								.and_then(|namespace| iri.strip_prefix(namespace.as_str()))
+							{
+								try_from_suffix.extend(quote! {
+									#suffix => Some(#type_id::#variant_ident),
+								});
+							}
+						} else if let Some(path) = &variant_options.expr {
+							// The IRI is only known at runtime.
+							let message = format!("invalid IRI for variant `{}`", variant_ident);
+							let iri = quote! { ::iref::Iri::new(#path).expect(#message) };
+							has_expr = true;
+							iri_consts.push((
+								RenameRule::Snake
+									.apply(&variant_ident.to_string())
+									.to_uppercase(),
+								quote! { #path },
+								variant_ident.clone(),
+							));
+
+							expr_arms.extend(quote! {
+								__iref_enum_s if __iref_enum_s == #path => return Ok(#type_id::#variant_ident),
+							});
+
+							expr_equivalent_arms.extend(quote! {
+								_ if __iref_enum_comparable && ::iref::Iri::new(#path).is_ok_and(|__iref_enum_expected| __iref_enum_iri == __iref_enum_expected) => Ok(#type_id::#variant_ident),
+							});
+
+							declared_unit_iris.push((
+								variant_ident.to_string(),
+								quote! { #path },
+								quote! { #type_id::#variant_ident },
+							));
+
+							into.extend(quote! {
+								#type_id::#variant_ident => #iri,
+							});
+
+							owned_into.extend(quote! {
+								#type_id::#variant_ident => Ok(#iri.to_owned()),
+							});
+
+							str_eq.extend(quote! {
+								#type_id::#variant_ident => __iref_enum_other == #path,
+							});
+
+							from_iri_const.extend(quote! {
+								if __iref_enum_eq(__iref_enum_s, #path.as_bytes()) {
+									return Some(#type_id::#variant_ident);
+								}
+							});
+
+							group.extend(quote! {
+								#type_id::#variant_ident => #type_id::matching_prefix(#iri).unwrap_or_default(),
+							});
+						} else {
+							return error!("missing IRI for enum variant `{}`", variant_ident);
+						}
+					}
+					syn::Fields::Named(_) => {
+						return error!("variants with named fields are unsupported")
+					}
+					syn::Fields::Unnamed(fields) => {
+						if fields.unnamed.len() == 1 {
+							let field = fields.unnamed.into_iter().next().unwrap();
+							let ty = field.ty;
+							unit_only = false;
+
+							if variant_options.preserve_original {
+								return error!(
+									"`preserve_original` option on variant `{}` without a second `IriBuf` field",
+									variant_ident
+								);
+							}
+
+							if is_self_type(&ty, &type_id) {
+								return error!(
+									"self-referential variant `{}` is unsupported",
+									variant_ident
+								);
+							}
+
+							if let Some(inner) = option_type(&ty) {
+								// The variant matches its own IRI with `None`, and
+								// any IRI of the inner type with `Some`.
+								let Some(iri) = variant_iri else {
+									return error!(
+										"variant `{}` with an optional field requires an IRI, matched by `None`",
+										variant_ident
+									);
+								};
+
+								if variant_options.transparent {
+									return error!(
+										"`transparent` option on variant `{}` with an optional field",
+										variant_ident
+									);
+								}
+
+								let normalized_iri = options.normalize(&iri);
+								let normalized_iri = normalized_iri.as_str();
+								let iri = iri.as_str();
+								let prefix =
+									longest_prefix(iri, &output_prefixes).unwrap_or_default();
+
+								unit_arms.push((
+									normalized_iri.to_owned(),
+									quote! { #type_id::#variant_ident(None) },
+									None,
+								));
+								declared_unit_iris.push((
+									variant_ident.to_string(),
+									quote! { #normalized_iri },
+									quote! { #type_id::#variant_ident(None) },
+								));
+								delegations.push((
+									quote! { #type_id::#variant_ident(Some(__iref_enum_value)) },
+									inner.clone(),
+								));
+
+								into.extend(quote! {
+									#type_id::#variant_ident(None) => ::iref_enum::iri!(#iri),
+									#type_id::#variant_ident(Some(__iref_enum_v)) => __iref_enum_v.into(),
+								});
+
+								owned_into.extend(quote! {
+									#type_id::#variant_ident(None) => Ok(::iref_enum::iri!(#iri).to_owned()),
+									#type_id::#variant_ident(Some(__iref_enum_v)) => Ok(<&::iref::Iri as From<&#inner>>::from(__iref_enum_v).to_owned()),
+								});
+
+								str_eq.extend(quote! {
+									#type_id::#variant_ident(None) => __iref_enum_other == #iri,
+									#type_id::#variant_ident(Some(__iref_enum_v)) => <&::iref::Iri as From<&#inner>>::from(__iref_enum_v).as_str() == __iref_enum_other,
+								});
+
+								group.extend(quote! {
+									#type_id::#variant_ident(None) => #prefix,
+									#type_id::#variant_ident(Some(__iref_enum_v)) => {
+										#type_id::matching_prefix(<&::iref::Iri as From<&#inner>>::from(__iref_enum_v)).unwrap_or_default()
+									}
+								});
+
+								continue;
+							}
+
+							if let Some(base) = variant_iri {
+								// The variant captures the remainder of any IRI
+								// starting with its own IRI.
+								// The input IRI is normalized before matching,
+								// so the base is compared in its normalized
+								// form.
+								let normalized_base = options.normalize(&base);
+								let normalized_base = normalized_base.as_str();
+								let base = base.as_str();
+								let prefix =
+									longest_prefix(base, &output_prefixes).unwrap_or_default();
+								has_capture = true;
+
+								// The remainder is taken from the original IRI
+								// when normalization left it unchanged (up to
+								// case), so the captured value keeps the input
+								// casing.
+								let rest = if options.runtime_normalization().is_empty() {
+									proc_macro2::TokenStream::new()
+								} else {
+									let unchanged = if options.case_insensitive {
+										quote! { __iref_enum_original_rest.to_lowercase() == __iref_enum_rest }
+									} else {
+										quote! { *__iref_enum_original_rest == __iref_enum_rest }
+									};
+
+									quote! {
+										let __iref_enum_original_str = __iref_enum_original.as_str();
+										let __iref_enum_rest = __iref_enum_original_str
+											.len()
+											.checked_sub(__iref_enum_rest.len())
+											.and_then(|__iref_enum_i| __iref_enum_original_str.get(__iref_enum_i..))
+											.filter(|__iref_enum_original_rest| #unchanged)
+											.unwrap_or(__iref_enum_rest);
+									}
+								};
+
+								captures.extend(quote! {
+									if let Some(__iref_enum_rest) = __iref_enum_iri.as_str().strip_prefix(#normalized_base) {
+										#rest
+										if let Ok(__iref_enum_value) = <#ty as ::std::str::FromStr>::from_str(__iref_enum_rest) {
+											return Ok(#type_id::#variant_ident(__iref_enum_value));
+										}
+									}
+								});
+
+								owned_into.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => ::iref::IriBuf::new(format!("{}{}", #base, __iref_enum_v)),
+								});
+
+								group.extend(quote! {
+									#type_id::#variant_ident(_) => #prefix,
+								});
+
+								str_eq.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => __iref_enum_other.strip_prefix(#base).is_some_and(|__iref_enum_rest| __iref_enum_rest == __iref_enum_v.to_string().as_str()),
+								});
+
+								continue;
+							}
+
+							if variant_options.default {
+								// The variant captures any other IRI into its
+								// owned `IriBuf`.
+								if default_variant.replace(variant_ident.clone()).is_some() {
+									return error!("multiple default variants");
+								}
+								has_default = true;
+
+								into.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => ::iref::IriBuf::as_iri(__iref_enum_v),
+								});
+
+								owned_into.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => Ok(::iref::IriBuf::clone(__iref_enum_v)),
+								});
+
+								str_eq.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => __iref_enum_v.as_str() == __iref_enum_other,
+								});
+
+								group.extend(quote! {
+									#type_id::#variant_ident(__iref_enum_v) => {
+										#type_id::matching_prefix(__iref_enum_v).unwrap_or_default()
+									}
+								});
+
+								continue;
+							}
+
+							if variant_options.catch {
+								if variant_options.transparent {
+									return error!(
+										"`catch` option on transparent variant `{}`",
+										variant_ident
+									);
+								}
+
+								if catch_variant.is_some() {
+									return error!("multiple catch variants");
+								}
+
+								catch_variant = Some((
+									quote! { #type_id::#variant_ident(__iref_enum_value) },
+									ty.clone(),
+								));
+							} else if !variant_options.transparent {
+								delegations.push((
+									quote! { #type_id::#variant_ident(__iref_enum_value) },
+									ty.clone(),
+								));
+							}
+
+							into.extend(quote! {
+								#type_id::#variant_ident(__iref_enum_v) => __iref_enum_v.into(),
+							});
+
+							owned_into.extend(quote! {
+								#type_id::#variant_ident(__iref_enum_v) => Ok(<&::iref::Iri as From<&#ty>>::from(__iref_enum_v).to_owned()),
+							});
+
+							str_eq.extend(quote! {
+								#type_id::#variant_ident(__iref_enum_v) => <&::iref::Iri as From<&#ty>>::from(__iref_enum_v).as_str() == __iref_enum_other,
+							});
+
+							group.extend(quote! {
+								#type_id::#variant_ident(__iref_enum_v) => {
+									#type_id::matching_prefix(<&::iref::Iri as From<&#ty>>::from(__iref_enum_v)).unwrap_or_default()
+								}
+							});
+						} else if fields.unnamed.len() == 2 && variant_options.preserve_original {
+							// The variant wraps a value along with the IRI it
+							// was matched from, returned as its IRI.
+							let mut fields = fields.unnamed.into_iter();
+							let ty = fields.next().unwrap().ty;
+							let original_ty = fields.next().unwrap().ty;
+							unit_only = false;
+							has_original = true;
+
+							if is_self_type(&ty, &type_id) {
+								return error!(
+									"self-referential variant `{}` is unsupported",
+									variant_ident
+								);
+							}
+
+							let is_iri_buf = matches!(&original_ty, syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "IriBuf"));
+							if !is_iri_buf {
+								return error!(
+									"the second field of variant `{}` must be an `IriBuf`",
+									variant_ident
+								);
+							}
+
+							let term = quote! { #type_id::#variant_ident(__iref_enum_value, ::iref::Iri::to_owned(__iref_enum_original)) };
+							if variant_options.catch {
+								if variant_options.transparent {
+									return error!(
+										"`catch` option on transparent variant `{}`",
+										variant_ident
+									);
+								}
+
+								if catch_variant.is_some() {
+									return error!("multiple catch variants");
+								}
+
+								catch_variant = Some((term, ty.clone()));
+							} else if !variant_options.transparent {
+								delegations.push((term, ty.clone()));
+							}
+
+							into.extend(quote! {
+								#type_id::#variant_ident(_, __iref_enum_o) => ::iref::IriBuf::as_iri(__iref_enum_o),
+							});
+
+							owned_into.extend(quote! {
+								#type_id::#variant_ident(_, __iref_enum_o) => Ok(::iref::IriBuf::clone(__iref_enum_o)),
+							});
+
+							str_eq.extend(quote! {
+								#type_id::#variant_ident(_, __iref_enum_o) => __iref_enum_o.as_str() == __iref_enum_other,
+							});
+
+							group.extend(quote! {
+								#type_id::#variant_ident(_, __iref_enum_o) => {
+									#type_id::matching_prefix(__iref_enum_o).unwrap_or_default()
+								}
+							});
+						} else {
+							return error!(
+								"variants with named more than one field are unsupported"
+							);
+						}
+					}
+				}
+			}
+
+			// Wrapped variants are tried from the last one to the first,
+			// before the longest prefix, catch and default variants.
+			let mut try_from_default = match (&default_variant, catch_variant) {
+				(Some(_), Some(_)) => {
+					return error!("the `catch` and `default` options are exclusive")
+				}
+				(Some(variant_ident), None) => {
+					quote! { Ok(#type_id::#variant_ident(__iref_enum_iri.to_owned())) }
+				}
+				(None, Some((term, ty))) => quote! {
+					match <#ty as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri) {
+						Ok(__iref_enum_value) => Ok(#term),
+						Err(_) => Err(()),
+					}
+				},
+				(None, None) => quote! { Err(()) },
+			};
+			if options.longest_prefix {
+				let mut prefix_arms = unit_arms.clone();
+				prefix_arms.sort_by_key(|(iri, ..)| std::cmp::Reverse(iri.len()));
+				// A prefix only matches up to a component boundary: it must
+				// end with a delimiter, or be followed by one.
+				let prefix_arm_matches = prefix_arms.iter().map(|(iri, ..)| {
+					if iri.ends_with(['/', '#', ':']) {
+						quote! { __iref_enum_iri.as_str().starts_with(#iri) }
+					} else {
+						quote! {
+							__iref_enum_iri.as_str().strip_prefix(#iri).is_some_and(|__iref_enum_rest| {
+								__iref_enum_rest.is_empty() || __iref_enum_rest.starts_with(['/', '#', '?', ':'])
+							})
+						}
+					}
+				});
+				let prefix_arm_variants = prefix_arms.iter().map(|(_, variant, _)| variant);
+				let prefix_arm_guards = prefix_arms.iter().map(|(.., guard)| match guard {
+					Some(guard) => quote! { && #guard() },
+					None => proc_macro2::TokenStream::new(),
+				});
+				try_from_default = quote! {
+					#(
+						if #prefix_arm_matches #prefix_arm_guards {
+							return Ok(#prefix_arm_variants);
+						}
+					)*
+					#try_from_default
+				};
+			}
+			for (term, ty) in delegations {
+				try_from_default = quote! {
+					match <#ty as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri) {
+						Ok(__iref_enum_value) => Ok(#term),
+						Err(_) => {
+							#try_from_default
+						}
+					}
+				};
+			}
+
+			// Constants generated for unit-only enums.
+			for (name, _, variant_ident) in &iri_consts {
+				if name == "VARIANTS" || name == "VARIANT_IRIS" {
+					return error!(
+						"variant `{}` has the IRI constant name `{}`, reserved for a generated constant",
+						variant_ident, name
+					);
+				}
+			}
+
+			iri_consts.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+			for pair in iri_consts.windows(2) {
+				if pair[0].0 == pair[1].0 {
+					return error!(
+						"variants `{}` and `{}` have the same IRI constant name `{}`",
+						pair[0].2, pair[1].2, pair[0].0
+					);
+				}
+			}
+			let iri_const_idents = iri_consts
+				.iter()
+				.map(|(name, ..)| quote::format_ident!("{}", name));
+			let iri_const_docs = iri_consts.iter().map(|(.., variant)| {
+				let doc = format!("IRI of [`{}::{}`].", type_id, variant);
+				variant_doc(&doc, &variant_docs[&variant.to_string()])
+			});
+			let iri_const_values = iri_consts.iter().map(|(_, value, _)| value);
+			let iri_consts = quote! {
+				#[automatically_derived]
+				impl #type_id {
+					#(
+						#iri_const_docs
+						#vis const #iri_const_idents: &'static str = #iri_const_values;
+					)*
+				}
+			};
+
+			let declared_unit_variants: Vec<_> = declared_unit_iris
+				.iter()
+				.map(|(variant, ..)| variant)
+				.collect();
+			let declared_unit_count = declared_unit_iris.len();
+			let declared_unit_terms: Vec<_> =
+				declared_unit_iris.iter().map(|(.., term)| term).collect();
+			let declared_unit_iris: Vec<_> =
+				declared_unit_iris.iter().map(|(_, iri, _)| iri).collect();
+
+			// Declared IRIs always take precedence over `trim_suffix`.
+			for (iri, term, guard, variant_ident) in trimmed_arms {
+				if unit_arms.iter().any(|(other, ..)| *other == iri) {
+					return error!(
+						"IRI `{}`, matched by variant `{}` through its `trim_suffix` option, is declared by another variant",
+						iri, variant_ident
+					);
+				}
+
+				unit_arms.push((iri, term, guard))
+			}
+
+			// Unit arms are sorted by IRI, which lets the compiler optimize
+			// the string match. The (stable) sort keeps the first declared
+			// variant among variants sharing the same IRI, along with the
+			// guarded variants declared before it, which fall through to the
+			// next one when their guard fails.
+			unit_arms.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+			unit_arms.dedup_by(|(b, ..), (a, _, a_guard)| a == b && a_guard.is_none());
+			let unit_arm_iris: Vec<_> = unit_arms.iter().map(|(iri, ..)| iri.as_str()).collect();
+			let unit_arm_variants: Vec<_> =
+				unit_arms.iter().map(|(_, variant, _)| variant).collect();
+
+			// Guarded arms only match when their guard returns `true`, falling
+			// through to the next arms otherwise.
+			let has_guard = unit_arms.iter().any(|(.., guard)| guard.is_some());
+			let unit_arm_guards: Vec<_> = unit_arms
+				.iter()
+				.map(|(.., guard)| match guard {
+					Some(guard) => quote! { if #guard() },
+					None => proc_macro2::TokenStream::new(),
+				})
+				.collect();
+			let unit_arm_and_guards = unit_arms.iter().map(|(.., guard)| match guard {
+				Some(guard) => quote! { && #guard() },
+				None => proc_macro2::TokenStream::new(),
+			});
+
+			let normalization = options.runtime_normalization();
+
+			// With `inline_data`, unit variants are listed in a table sorted
+			// by IRI, indexing the arms of a single `__iref_enum_from_index`
+			// function.
+			let data = if options.inline_data {
+				if has_guard {
+					return error!(
+						"the `inline_data` option is incompatible with guarded variants"
+					);
+				}
+
+				if unit_arms.len() > u16::MAX as usize + 1 {
+					return error!("the `inline_data` option supports at most 65536 unit variants");
+				}
+
+				let indexes = (0..unit_arms.len()).map(|i| i as u16);
+				let from_index_indexes = indexes.clone();
+				quote! {
+					/// Unit variant IRIs, sorted, with the index of the
+					/// variant given to `__iref_enum_from_index`.
+					static __IREF_ENUM_DATA: &[(&str, u16)] = &[#((#unit_arm_iris, #indexes)),*];
+
+					fn __iref_enum_from_index(__iref_enum_index: u16) -> #type_id {
+						match __iref_enum_index {
+							#(#from_index_indexes => #unit_arm_variants,)*
+							_ => unreachable!(),
+						}
+					}
+				}
+			} else {
+				proc_macro2::TokenStream::new()
+			};
+
+			// Slow path: the IRI is only equivalent to the IRI of a unit
+			// variant (e.g. with dot segments).
+			let slow_path = if options.match_str {
+				quote! {
+					#captures
+					#try_from_default
+				}
+			} else if options.inline_data {
+				quote! {
+					let __iref_enum_comparable = __iref_enum_is_comparable(__iref_enum_iri);
+					if __iref_enum_comparable {
+						for &(__iref_enum_data_iri, __iref_enum_index) in __IREF_ENUM_DATA {
+							// SAFETY: the table only holds the variant IRIs,
+							// validated while expanding the derive macro.
+							if __iref_enum_iri == unsafe { ::iref::Iri::new_unchecked(__iref_enum_data_iri) } {
+								return Ok(__iref_enum_from_index(__iref_enum_index));
+							}
+						}
+					}
+
+					match __iref_enum_iri {
+						#expr_equivalent_arms
+						_ => {
+							#captures
+							#try_from_default
+						}
+					}
+				}
+			} else {
+				let comparable = if unit_arms.is_empty() && !has_expr {
+					proc_macro2::TokenStream::new()
+				} else {
+					quote! { let __iref_enum_comparable = __iref_enum_is_comparable(__iref_enum_iri); }
+				};
+
+				quote! {
+					#comparable
+					match __iref_enum_iri {
+						#(_ if __iref_enum_comparable && __iref_enum_iri == ::iref_enum::iri!(#unit_arm_iris) #unit_arm_and_guards => Ok(#unit_arm_variants),)*
+						#expr_equivalent_arms
+						_ => {
+							#captures
+							#try_from_default
+						}
+					}
+				}
+			};
+
+			// Fast path: the IRI is syntactically equal to the IRI of a unit
+			// variant. The compiler checks the length of the IRI before
+			// comparing it with each arm of equal length, so when unit
+			// variants share a long common prefix (typically a namespace),
+			// it is compared once before matching the suffixes instead. On
+			// short prefixes the extra comparison costs more than it saves.
+			let common_prefix = if unit_arm_iris.len() > 1 {
+				common_prefix(&unit_arm_iris)
+			} else {
+				""
+			};
+			let mut fast_path = if options.inline_data {
+				quote! {
+					if let Ok(__iref_enum_i) = __IREF_ENUM_DATA.binary_search_by(|(__iref_enum_data_iri, _)| (*__iref_enum_data_iri).cmp(__iref_enum_iri.as_str())) {
+						return Ok(__iref_enum_from_index(__IREF_ENUM_DATA[__iref_enum_i].1));
+					}
+				}
+			} else if common_prefix.len() < COMMON_PREFIX_MIN_LEN {
+				quote! {
+					match __iref_enum_iri.as_str() {
+						#(#unit_arm_iris #unit_arm_guards => return Ok(#unit_arm_variants),)*
+						_ => ()
+					}
+				}
+			} else {
+				let unit_arm_suffixes = unit_arm_iris.iter().map(|iri| &iri[common_prefix.len()..]);
+				quote! {
+					if let Some(__iref_enum_suffix) = __iref_enum_iri.as_str().strip_prefix(#common_prefix) {
+						match __iref_enum_suffix {
+							#(#unit_arm_suffixes #unit_arm_guards => return Ok(#unit_arm_variants),)*
+							_ => ()
+						}
+					}
+				}
+			};
+			if has_expr {
+				fast_path.extend(quote! {
+					match __iref_enum_iri.as_str() {
+						#expr_arms
+						_ => ()
+					}
+				})
+			}
+
+			// IRIs with another scheme cannot be equivalent to the IRI of a
+			// unit variant (schemes are case insensitive).
+			let scheme_check = match &options.schemes {
+				Some(schemes) => {
+					if has_expr {
+						return error!(
+							"the `schemes` option is incompatible with the `expr` option"
+						);
+					}
+
+					for (iri, ..) in &unit_arms {
+						let scheme = iri.split(':').next().unwrap_or_default().to_lowercase();
+						if !schemes.contains(&scheme) {
+							return error!(
+								"IRI `{}` uses a scheme not listed by the `schemes` option",
+								iri
+							);
+						}
+					}
+
+					quote! {
+						let __iref_enum_scheme = __iref_enum_iri.scheme().as_str();
+						if !(#(__iref_enum_scheme.eq_ignore_ascii_case(#schemes))||*) {
+							return {
+								#captures
+								#try_from_default
+							};
+						}
+					}
+				}
+				None => proc_macro2::TokenStream::new(),
+			};
+
+			// Preserved IRIs are the IRIs given to the conversion, before any
+			// normalization.
+			let original = if has_original || (has_capture && !normalization.is_empty()) {
+				quote! { let __iref_enum_original: &::iref::Iri = __iref_enum_iri; }
+			} else {
+				proc_macro2::TokenStream::new()
+			};
+
+			let mut try_from_body = quote! {
+				#original
+				#normalization
+				#scheme_check
+				#fast_path
+				#slow_path
+			};
+
+			// Ambiguous local names are left out.
+			let mut local_name_counts: HashMap<&str, usize> = HashMap::new();
+			for (name, _) in &local_names {
+				*local_name_counts.entry(name.as_str()).or_default() += 1
+			}
+			let (local_name_arms, local_name_terms): (Vec<_>, Vec<_>) = local_names
+				.iter()
+				.filter(|(name, _)| !name.is_empty() && local_name_counts[name.as_str()] == 1)
+				.map(|(name, variant)| (name.as_str(), quote! { #type_id::#variant }))
+				.unzip();
+
+			// With `borrow_error`, the input IRI is given back on failure.
+			// Other generated items only need to know whether the conversion
+			// succeeded, and convert the error back into `()` when needed.
+			let (try_from_error, unit_error) = if options.borrow_error {
+				(quote! { &'a ::iref::Iri }, quote! { .map_err(|_| ()) })
+			} else {
+				(quote! { () }, proc_macro2::TokenStream::new())
+			};
+
+			let mut metrics = proc_macro2::TokenStream::new();
+			if cfg!(feature = "metrics") {
+				let len = declared_unit_terms.len();
+				let indexes = 0..len;
+				try_from_body = quote! {
+					let __iref_enum_result = (|| -> ::std::result::Result<#type_id, ()> { #try_from_body })();
+					if let Ok(__iref_enum_term) = &__iref_enum_result {
+						match __iref_enum_term {
+							#(#declared_unit_terms => {
+								__IREF_ENUM_MATCH_COUNTS[#indexes].fetch_add(1, ::std::sync::atomic::Ordering::Relaxed);
+							})*
+							_ => (),
+						}
+					}
+					__iref_enum_result
+				};
+
+				let indexes = 0..len;
+				metrics = quote! {
+					/// Number of conversions resulting in each unit variant.
+					static __IREF_ENUM_MATCH_COUNTS: [::std::sync::atomic::AtomicU64; #len] =
+						[const { ::std::sync::atomic::AtomicU64::new(0) }; #len];
+
+					#[automatically_derived]
+					impl #type_id {
+						/// Returns the number of `TryFrom<&Iri>` conversions that
+						/// resulted in each unit variant, since the start of the
+						/// program.
+						#vis fn match_counts() -> Vec<(Self, u64)> {
+							vec![#((#declared_unit_terms, __IREF_ENUM_MATCH_COUNTS[#indexes].load(::std::sync::atomic::Ordering::Relaxed))),*]
+						}
+					}
+				};
+			}
+
+			if options.borrow_error {
+				try_from_body = quote! {
+					(|| -> ::std::result::Result<#type_id, ()> { #try_from_body })().map_err(|()| __iref_enum_iri)
+				};
+			}
+
+			let mut output = quote! {
+				#metrics
+				#data
+
+				/// Checks that the given IRI can be compared (or hashed).
+				///
+				/// Comparing IRIs decodes their percent-encoded sequences, and
+				/// panics if they are not valid UTF-8.
+				fn __iref_enum_is_comparable(__iref_enum_iri: &::iref::Iri) -> bool {
+					!__iref_enum_iri.as_str().contains('%') || {
+						let __iref_enum_bytes = __iref_enum_iri.as_str().as_bytes();
+						let mut __iref_enum_decoded = Vec::with_capacity(__iref_enum_bytes.len());
+						let mut __iref_enum_i = 0;
+						while __iref_enum_i < __iref_enum_bytes.len() {
+							match (__iref_enum_bytes[__iref_enum_i], __iref_enum_bytes.get(__iref_enum_i + 1..__iref_enum_i + 3)) {
+								(b'%', Some(&[__iref_enum_high, __iref_enum_low])) => {
+									let __iref_enum_digit = |__iref_enum_b: u8| (__iref_enum_b as char).to_digit(16).unwrap_or_default() as u8;
+									__iref_enum_decoded.push(__iref_enum_digit(__iref_enum_high) << 4 | __iref_enum_digit(__iref_enum_low));
+									__iref_enum_i += 3
+								}
+								(__iref_enum_b, _) => {
+									__iref_enum_decoded.push(__iref_enum_b);
+									__iref_enum_i += 1
+								}
+							}
+						}
+						::std::str::from_utf8(&__iref_enum_decoded).is_ok()
+					}
+				}
+
+				#[automatically_derived]
+				impl<'a> ::std::convert::TryFrom<&'a ::iref::Iri> for #type_id {
+					type Error = #try_from_error;
+
+					#[inline]
+					fn try_from(__iref_enum_iri: &'a ::iref::Iri) -> ::std::result::Result<#type_id, #try_from_error> {
+						#try_from_body
+					}
+				}
+
+				#[automatically_derived]
+				impl #type_id {
+					/// Returns the name of the declared prefix under which the
+					/// IRI of this term falls (longest match), or `""` if none.
+					#vis fn group(&self) -> &'static str {
+						match self {
+							#group
+						}
+					}
+
+					/// Checks if this term is a variant carrying a field, rather
+					/// than a fixed term of the vocabulary.
+					#vis fn is_wrapped(&self) -> bool {
+						match self {
+							#wrapped
+							_ => false,
+						}
+					}
+
+					/// Returns the namespace IRI of this term, if it is a
+					/// prefix-only variant.
+					#[must_use]
+					#vis fn base_iri(&self) -> Option<&'static ::iref::Iri> {
+						match self {
+							#base_iri
+							_ => None,
+						}
+					}
 
-macro_rules! error {
-	( $( $x:expr ),* ) => {
-		{
-			let msg = format!($($x),*);
-			let tokens: TokenStream = format!("compile_error!(\"{}\");", msg).parse().unwrap();
-			tokens
-		}
-	};
-}
+					/// Checks if this term is deprecated.
+					#vis fn is_deprecated(&self) -> bool {
+						self.deprecation_note().is_some()
+					}
 
-fn filter_attribute(
-	attr: syn::Attribute,
-	name: &str,
-) -> Result<Option<proc_macro2::TokenStream>, TokenStream> {
-	if let Some(attr_id) = attr.path.get_ident() {
-		if attr_id == name {
-			if let Some(TokenTree::Group(group)) = attr.tokens.into_iter().next() {
-				Ok(Some(group.stream()))
+					/// Returns the deprecation note of this term, if it is
+					/// deprecated.
+					#[must_use]
+					#vis fn deprecation_note(&self) -> Option<&'static str> {
+						match self {
+							#deprecated
+							_ => None,
+						}
+					}
+
+					/// Classifies the given IRI strings, returning the matched
+					/// terms and the unmatched strings.
+					///
+					/// Strings that are not valid IRIs are unmatched.
+					#[must_use]
+					#vis fn parse_all(__iref_enum_iris: &[&str]) -> (Vec<Self>, Vec<String>) {
+						let mut __iref_enum_terms = Vec::new();
+						let mut __iref_enum_unmatched = Vec::new();
+
+						for &__iref_enum_s in __iref_enum_iris {
+							match ::iref::Iri::new(__iref_enum_s).ok().and_then(|__iref_enum_iri| <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).ok()) {
+								Some(__iref_enum_term) => __iref_enum_terms.push(__iref_enum_term),
+								None => __iref_enum_unmatched.push(__iref_enum_s.to_owned()),
+							}
+						}
+
+						(__iref_enum_terms, __iref_enum_unmatched)
+					}
+
+					/// Returns the unit term whose IRI has the given local name
+					/// (the fragment, or else the last path segment), whatever
+					/// its namespace.
+					///
+					/// Returns `None` if no term, or more than one, has this
+					/// local name.
+					#[must_use]
+					#vis fn by_local_name(__iref_enum_local: &str) -> Option<Self> {
+						match __iref_enum_local {
+							#(#local_name_arms => Some(#local_name_terms),)*
+							_ => None,
+						}
+					}
+
+					/// Returns the term matching the first of the given IRIs that
+					/// matches any term, if any.
+					///
+					/// This is useful to match data using alternative spellings of
+					/// the same IRI.
+					#[must_use]
+					#vis fn matches_any(__iref_enum_iris: &[&::iref::Iri]) -> Option<Self> {
+						__iref_enum_iris.iter().find_map(|__iref_enum_iri| <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).ok())
+					}
+
+					/// Returns the name of the declared prefix whose IRI is the
+					/// longest prefix of the given IRI, if any.
+					///
+					/// The IRI does not need to be the IRI of a term.
+					#[must_use]
+					#vis fn matching_prefix(__iref_enum_iri: &::iref::Iri) -> Option<&'static str> {
+						let __iref_enum_iri = __iref_enum_iri.as_str();
+						#(
+							if __iref_enum_iri.starts_with(#output_prefix_iris) {
+								return Some(#output_prefix_names);
+							}
+						)*
+						None
+					}
+
+					/// Checks if a prefix with the given name is declared on the
+					/// enum (secondary prefixes included).
+					#vis fn has_prefix(__iref_enum_name: &str) -> bool {
+						match __iref_enum_name {
+							#(#sorted_prefix_names => true,)*
+							_ => false,
+						}
+					}
+
+					/// Returns the term matching the given IRI, looking it up in
+					/// the given cache first, and inserting it on miss.
+					///
+					/// The cache is keyed by the matched IRI, so it does not
+					/// require `Self` to implement `Clone` or `Eq`: the cached
+					/// term is returned by reference. IRIs whose
+					/// percent-encoded sequences are not valid UTF-8 cannot be
+					/// hashed, so they are never matched.
+					#[must_use]
+					#vis fn from_iri_cached<'c>(
+						__iref_enum_iri: &::iref::Iri,
+						__iref_enum_cache: &'c mut ::std::collections::HashMap<::iref::IriBuf, Self>
+					) -> Option<&'c Self> {
+						if !__iref_enum_is_comparable(__iref_enum_iri) {
+							return None;
+						}
+
+						if !__iref_enum_cache.contains_key(__iref_enum_iri) {
+							let __iref_enum_term = <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).ok()?;
+							__iref_enum_cache.insert(__iref_enum_iri.to_owned(), __iref_enum_term);
+						}
+
+						__iref_enum_cache.get(__iref_enum_iri)
+					}
+
+					/// Returns the term matching the IRI of the given value,
+					/// such as a wrapper type around an IRI, if any.
+					#[must_use]
+					#vis fn from_any(__iref_enum_iri: &impl AsRef<::iref::Iri>) -> Option<Self> {
+						<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri.as_ref()).ok()
+					}
+
+					/// Expands the given compact IRI using the given runtime
+					/// context, then returns the matching term, if any.
+					///
+					/// Prefixes not found in the context are looked up in the
+					/// declared prefixes. Values that are not compact IRIs
+					/// (or whose prefix is nowhere declared) are parsed as
+					/// absolute IRIs.
+					#[must_use]
+					#vis fn from_compact(__iref_enum_value: &str, __iref_enum_context: &[(&str, &::iref::Iri)]) -> Option<Self> {
+						let __iref_enum_expanded = match __iref_enum_value.split_once(':') {
+							Some((__iref_enum_prefix, __iref_enum_suffix)) if !__iref_enum_prefix.is_empty() && !__iref_enum_suffix.starts_with("//") => {
+								let __iref_enum_base_iri = match __iref_enum_context.iter().find(|(__iref_enum_name, _)| *__iref_enum_name == __iref_enum_prefix) {
+									Some((_, __iref_enum_base_iri)) => Some(__iref_enum_base_iri.as_str()),
+									None => match __iref_enum_prefix {
+										#(#sorted_prefix_names => Some(#sorted_prefix_iris),)*
+										_ => None,
+									},
+								};
+
+								__iref_enum_base_iri.map(|__iref_enum_base_iri| format!("{}{}", __iref_enum_base_iri, __iref_enum_suffix))
+							}
+							_ => None,
+						};
+
+						let __iref_enum_iri = ::iref::Iri::new(__iref_enum_expanded.as_deref().unwrap_or(__iref_enum_value)).ok()?;
+						<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).ok()
+					}
+				}
+			};
+
+			output.extend(iri_consts);
+
+			output.extend(quote! {
+				#[automatically_derived]
+				impl PartialEq<str> for #type_id {
+					fn eq(&self, __iref_enum_other: &str) -> bool {
+						match self {
+							#str_eq
+						}
+					}
+				}
+
+				#[automatically_derived]
+				impl<'a> PartialEq<&'a str> for #type_id {
+					#[inline]
+					fn eq(&self, __iref_enum_other: &&'a str) -> bool {
+						<#type_id as PartialEq<str>>::eq(self, __iref_enum_other)
+					}
+				}
+			});
+
+			if options.parse_only {
+				// Only the conversions from IRIs are generated.
+			} else if has_capture {
+				// Capturing variants have no `'static` IRI.
+				output.extend(quote! {
+					#[automatically_derived]
+					impl<'a> ::std::convert::TryFrom<&'a #type_id> for ::iref::IriBuf {
+						type Error = ::iref::InvalidIri<String>;
+
+						fn try_from(__iref_enum_vocab: &'a #type_id) -> ::std::result::Result<::iref::IriBuf, ::iref::InvalidIri<String>> {
+							match __iref_enum_vocab {
+								#owned_into
+							}
+						}
+					}
+				})
 			} else {
-				Err(error!("malformed `{}` attribute", name))
-			}
-		} else {
-			Ok(None)
-		}
-	} else {
-		Ok(None)
-	}
-}
+				// With a default variant, or a variant preserving the
+				// original IRI, the IRI is borrowed from the term.
+				let borrowed = has_default || has_original;
+				let (lifetime, impl_generics) = if borrowed {
+					(quote! { 'a }, quote! { <'a> })
+				} else {
+					(quote! { 'i }, quote! { <'a, 'i> })
+				};
+				let static_lifetime = if borrowed {
+					proc_macro2::TokenStream::new()
+				} else {
+					quote! { 'static }
+				};
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #impl_generics From<&'a #type_id> for &#lifetime ::iref::Iri {
+						#[inline]
+						fn from(__iref_enum_vocab: &'a #type_id) -> &#lifetime ::iref::Iri {
+							match __iref_enum_vocab {
+								#into
+							}
+						}
+					}
 
-fn expand_iri(value: &str, prefixes: &HashMap<String, IriBuf>) -> Result<IriBuf, ()> {
-	if let Some(index) = value.find(':') {
-		if index > 0 {
-			let (prefix, suffix) = value.split_at(index);
-			let suffix = &suffix[1..suffix.len()];
+					#[automatically_derived]
+					impl #impl_generics From<&'a #type_id> for &#lifetime ::iref::IriRef {
+						#[inline]
+						fn from(__iref_enum_vocab: &'a #type_id) -> &#lifetime ::iref::IriRef {
+							<&::iref::Iri as From<&#type_id>>::from(__iref_enum_vocab).as_iri_ref()
+						}
+					}
 
-			if !suffix.starts_with("//") {
-				if let Some(base_iri) = prefixes.get(prefix) {
-					let concat = base_iri.as_str().to_string() + suffix;
-					if let Ok(iri) = IriBuf::new(concat) {
-						return Ok(iri);
-					} else {
-						return Err(());
+					#[automatically_derived]
+					impl #type_id {
+						/// Returns the IRI of this term.
+						///
+						/// For wrapped variants, this is the IRI of the field.
+						///
+						/// With the `sophia` option, this method shadows
+						/// `Term::iri` in method-call syntax.
+						#[inline]
+						#vis fn iri(&self) -> &#static_lifetime ::iref::Iri {
+							<&::iref::Iri as From<&#type_id>>::from(self)
+						}
+
+						/// Maps each of the given terms to its IRI.
+						#vis fn iris_of(__iref_enum_items: &[Self]) -> Vec<&#static_lifetime str> {
+							__iref_enum_items.iter().map(|__iref_enum_item| __iref_enum_item.iri().as_str()).collect()
+						}
+
+						/// Returns the declared IRI of the term matching the given
+						/// IRI, or the given IRI itself if no term matches it.
+						///
+						/// This maps the forms accepted by `TryFrom<&Iri>` (e.g.
+						/// under the normalization options) to the canonical IRI.
+						#vis fn normalize(__iref_enum_iri: &::iref::Iri) -> ::iref::IriBuf {
+							match <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri) {
+								Ok(__iref_enum_term) => __iref_enum_term.iri().to_owned(),
+								Err(_) => __iref_enum_iri.to_owned(),
+							}
+						}
+
+						/// Returns the compact IRI `prefix:suffix` of this term,
+						/// using the longest matching declared prefix, if any.
+						#[must_use]
+						#vis fn to_compact(&self) -> Option<String> {
+							let __iref_enum_iri = self.iri().as_str();
+							#(
+								if let Some(__iref_enum_suffix) = __iref_enum_iri.strip_prefix(#compact_prefix_iris) {
+									return Some(format!("{}:{}", #compact_prefix_names, __iref_enum_suffix));
+								}
+							)*
+							None
+						}
+
+						/// Returns the Turtle/N3 form of this term: its compact
+						/// IRI when a declared prefix matches and the suffix is
+						/// a simple local name, or `<iri>` otherwise.
+						#vis fn to_turtle_term(&self) -> String {
+							match self.to_compact() {
+								Some(__iref_enum_compact) if {
+									let (_, __iref_enum_suffix) = __iref_enum_compact.split_once(':').unwrap();
+									!__iref_enum_suffix.ends_with('.')
+										&& !__iref_enum_suffix.starts_with(['-', '.'])
+										&& __iref_enum_suffix.chars().all(|__iref_enum_c| __iref_enum_c.is_alphanumeric() || matches!(__iref_enum_c, '_' | '-' | '.'))
+								} => __iref_enum_compact,
+								_ => format!("<{}>", self.iri())
+							}
+						}
 					}
+
+					#[automatically_derived]
+					impl AsRef<::iref::Iri> for #type_id {
+						#[inline]
+						fn as_ref(&self) -> &::iref::Iri {
+							<&::iref::Iri as From<&#type_id>>::from(self)
+						}
+					}
+
+					#[automatically_derived]
+					impl AsRef<::iref::IriRef> for #type_id {
+						#[inline]
+						fn as_ref(&self) -> &::iref::IriRef {
+							<&::iref::IriRef as From<&#type_id>>::from(self)
+						}
+					}
+				});
+
+				if !borrowed {
+					output.extend(quote! {
+						#[automatically_derived]
+						impl<'i> From<#type_id> for &'i ::iref::Iri {
+							#[inline]
+							fn from(__iref_enum_vocab: #type_id) -> &'i ::iref::Iri {
+								<&::iref::Iri as From<&#type_id>>::from(&__iref_enum_vocab)
+							}
+						}
+
+						#[automatically_derived]
+						impl<'i> From<#type_id> for &'i ::iref::IriRef {
+							#[inline]
+							fn from(__iref_enum_vocab: #type_id) -> &'i ::iref::IriRef {
+								<&::iref::Iri as From<#type_id>>::from(__iref_enum_vocab).as_iri_ref()
+							}
+						}
+
+						#[automatically_derived]
+						impl #type_id {
+							/// Returns an iterator over the unit terms, in
+							/// declaration order, with their IRI and compact IRI
+							/// (see `to_compact`).
+							#vis fn describe() -> impl Iterator<Item = (Self, &'static ::iref::Iri, Option<String>)> {
+								let __iref_enum_terms: [Self; #declared_unit_count] = [#(#declared_unit_terms),*];
+								__iref_enum_terms.into_iter().map(|__iref_enum_term| {
+									let __iref_enum_iri = __iref_enum_term.iri();
+									let __iref_enum_compact = __iref_enum_term.to_compact();
+									(__iref_enum_term, __iref_enum_iri, __iref_enum_compact)
+								})
+							}
+						}
+					})
 				}
 			}
-		}
-	}
 
-	if let Ok(iri) = IriBuf::new(value.to_owned()) {
-		Ok(iri)
-	} else {
-		Err(())
-	}
-}
+			if cfg!(feature = "suggestions") {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Returns the unit variant whose IRI is the closest to
+						/// the given IRI, with their Levenshtein distance (in
+						/// characters), if any.
+						///
+						/// This is meant for "did you mean?" diagnostics.
+						#[must_use]
+						#vis fn closest(__iref_enum_iri: &::iref::Iri) -> Option<(Self, usize)> {
+							let __iref_enum_a: Vec<char> = __iref_enum_iri.as_str().chars().collect();
+							let mut __iref_enum_row: Vec<usize> = Vec::with_capacity(__iref_enum_a.len() + 1);
+							let mut __iref_enum_best: Option<(Self, usize)> = None;
+							#(
+								__iref_enum_row.clear();
+								__iref_enum_row.extend(0..=__iref_enum_a.len());
+								for (__iref_enum_j, __iref_enum_b) in #unit_arm_iris.chars().enumerate() {
+									let mut __iref_enum_diagonal = __iref_enum_row[0];
+									__iref_enum_row[0] = __iref_enum_j + 1;
+									for __iref_enum_i in 0..__iref_enum_a.len() {
+										let __iref_enum_cost = __iref_enum_diagonal + usize::from(__iref_enum_a[__iref_enum_i] != __iref_enum_b);
+										__iref_enum_diagonal = __iref_enum_row[__iref_enum_i + 1];
+										__iref_enum_row[__iref_enum_i + 1] = __iref_enum_cost
+											.min(__iref_enum_row[__iref_enum_i] + 1)
+											.min(__iref_enum_diagonal + 1);
+									}
+								}
 
-#[proc_macro_derive(IriEnum, attributes(iri_prefix, iri))]
-pub fn iri_enum_derive(input: TokenStream) -> TokenStream {
-	let ast: syn::DeriveInput = syn::parse(input).unwrap();
+								let __iref_enum_distance = __iref_enum_row[__iref_enum_a.len()];
+								if !matches!(&__iref_enum_best, Some((_, __iref_enum_d)) if *__iref_enum_d <= __iref_enum_distance) {
+									__iref_enum_best = Some((#unit_arm_variants, __iref_enum_distance));
+								}
+							)*
+							__iref_enum_best
+						}
+					}
+				})
+			}
 
-	let mut prefixes = HashMap::new();
-	for attr in ast.attrs {
-		match filter_attribute(attr, "iri_prefix") {
-			Ok(Some(tokens)) => {
-				let mut tokens = tokens.into_iter();
-				if let Some(token) = tokens.next() {
-					if let Ok(prefix) = string_literal_token(token) {
-						if tokens.next().is_some() {
-							if let Some(token) = tokens.next() {
-								if let Ok(iri) = string_literal_token(token) {
-									match IriBuf::new(iri) {
-										Ok(iri) => {
-											prefixes.insert(prefix, iri);
-										}
-										Err(e) => {
-											return error!(
-												"invalid IRI `{}` for prefix `{}`",
-												e.0, prefix
-											);
-										}
+			if unit_only {
+				let variant_count = variant_idents.len();
+				let variant_indexes = 0..variant_count;
+
+				// IRIs given by `expr` are only validated at runtime.
+				if !has_expr && !options.parse_only {
+					output.extend(quote! {
+						#[automatically_derived]
+						impl #type_id {
+							/// Returns the IRI of this term.
+							///
+							/// Unlike `iri`, this method can be evaluated in const
+							/// contexts.
+							#vis const fn static_iri(&self) -> &'static ::iref::Iri {
+								match self {
+									#into
+								}
+							}
+
+							/// IRIs of every term, in declaration order.
+							///
+							/// The IRI of a term is found at the index given by
+							/// `discriminant`.
+							#vis const VARIANT_IRIS: [&'static ::iref::Iri; Self::VARIANTS] = [
+								#(#type_id::#variant_idents.static_iri()),*
+							];
+						}
+					})
+				}
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Returns the term with the given IRI, if any.
+						///
+						/// Unlike `TryFrom<&Iri>`, this function can be evaluated
+						/// in const contexts, comparing the IRI strings byte per
+						/// byte.
+						#[must_use]
+						#vis const fn from_iri_const(__iref_enum_s: &str) -> Option<Self> {
+							const fn __iref_enum_eq(__iref_enum_a: &[u8], __iref_enum_b: &[u8]) -> bool {
+								if __iref_enum_a.len() != __iref_enum_b.len() {
+									return false;
+								}
+
+								let mut __iref_enum_i = 0;
+								while __iref_enum_i < __iref_enum_a.len() {
+									if __iref_enum_a[__iref_enum_i] != __iref_enum_b[__iref_enum_i] {
+										return false;
 									}
-								} else {
-									return error!("expected a string literal");
+
+									__iref_enum_i += 1
+								}
+
+								true
+							}
+
+							let __iref_enum_s = __iref_enum_s.as_bytes();
+							#from_iri_const
+							None
+						}
+
+						/// Returns the discriminant of this term.
+						#vis const fn as_code(&self) -> i64 {
+							match self {
+								#(#type_id::#variant_idents => #type_id::#variant_idents as i64,)*
+							}
+						}
+
+						/// Number of terms.
+						#vis const VARIANTS: usize = #variant_count;
+
+						/// Returns the position of this term in declaration order.
+						///
+						/// Unlike `as_code`, explicit discriminants are ignored.
+						#vis const fn discriminant(&self) -> usize {
+							match self {
+								#(#type_id::#variant_idents => #variant_indexes,)*
+							}
+						}
+					}
+				})
+			}
+
+			if options.namespace.is_some() {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Returns the term whose IRI is the concatenation of the
+						/// namespace and the given suffix, if any.
+						///
+						/// Only unit variants are considered.
+						#[must_use]
+						#vis fn try_from_suffix(__iref_enum_suffix: &str) -> Option<Self> {
+							match __iref_enum_suffix {
+								#try_from_suffix
+								_ => None,
+							}
+						}
+					}
+				})
+			}
+
+			// IRIs depending on the build environment may collide without the
+			// vocabulary declaration showing it.
+			if !env_vars.is_empty() || has_expr {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Checks that no two unit variants have the same IRI.
+						///
+						/// Some IRIs of this type are read from the build
+						/// environment or given by constants, so they are
+						/// not visible in its declaration.
+						#vis fn validate() -> Result<(), String> {
+							const __IREF_ENUM_IRIS: &[(&str, &str)] = &[#((#declared_unit_variants, #declared_unit_iris)),*];
+							for (__iref_enum_i, (__iref_enum_a, __iref_enum_iri)) in __IREF_ENUM_IRIS.iter().enumerate() {
+								for (__iref_enum_b, __iref_enum_other_iri) in &__IREF_ENUM_IRIS[__iref_enum_i + 1..] {
+									if __iref_enum_iri == __iref_enum_other_iri {
+										return Err(format!("variants `{}` and `{}` have the same IRI `{}`", __iref_enum_a, __iref_enum_b, __iref_enum_iri));
+									}
+								}
+							}
+
+							Ok(())
+						}
+					}
+				})
+			}
+
+			let to_iri = if options.parse_only {
+				quote! {
+					::iref::IriBuf::new(__IREF_ENUM_IRIS.iter().find(|(__iref_enum_a, _)| *__iref_enum_a == __iref_enum_name).unwrap().1.to_owned()).unwrap()
+				}
+			} else if has_capture {
+				quote! {
+					::iref::IriBuf::try_from(&__iref_enum_term).unwrap_or_else(|_| panic!("variant `{}` has no IRI", __iref_enum_name))
+				}
+			} else {
+				quote! { <&::iref::Iri as From<&#type_id>>::from(&__iref_enum_term).to_owned() }
+			};
+
+			output.extend(quote! {
+				#[cfg(test)]
+				#[automatically_derived]
+				impl #type_id {
+					/// Checks that every unit variant converts into its IRI and
+					/// back, and that no two unit variants have the same IRI.
+					///
+					/// This is only available in test builds.
+					///
+					/// # Panics
+					///
+					/// Panics if any check fails.
+					#vis fn assert_roundtrip() {
+						const __IREF_ENUM_IRIS: &[(&str, &str)] = &[#((#declared_unit_variants, #declared_unit_iris)),*];
+						for (__iref_enum_i, (__iref_enum_a, __iref_enum_iri)) in __IREF_ENUM_IRIS.iter().enumerate() {
+							for (__iref_enum_b, __iref_enum_other_iri) in &__IREF_ENUM_IRIS[__iref_enum_i + 1..] {
+								assert!(
+									__iref_enum_iri != __iref_enum_other_iri,
+									"variants `{}` and `{}` have the same IRI `{}`",
+									__iref_enum_a, __iref_enum_b, __iref_enum_iri
+								);
+							}
+						}
+
+						#(
+							let __iref_enum_name = #declared_unit_variants;
+							let __iref_enum_term = #declared_unit_terms;
+							let __iref_enum_iri: ::iref::IriBuf = #to_iri;
+							assert!(
+								matches!(<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri.as_iri()), Ok(#declared_unit_terms)),
+								"variant `{}` does not round-trip through `{}`",
+								__iref_enum_name, __iref_enum_iri
+							);
+						)*
+					}
+				}
+			});
+
+			if let Some(validator) = &options.validate_with {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Checks the IRI of every unit variant with the validator
+						/// given by the `validate_with` option, returning the
+						/// rejected variants.
+						#vis fn validate_iris() -> ::std::result::Result<(), Vec<Self>> {
+							let __iref_enum_validator: fn(&str) -> bool = #validator;
+							let mut __iref_enum_rejected = Vec::new();
+							#(
+								if !__iref_enum_validator(#declared_unit_iris) {
+									__iref_enum_rejected.push(#declared_unit_terms)
 								}
+							)*
+
+							if __iref_enum_rejected.is_empty() {
+								Ok(())
 							} else {
-								return error!("expected a string literal");
+								Err(__iref_enum_rejected)
 							}
-						} else {
-							return error!("expected `=` literal");
 						}
-					} else {
-						return error!("expected a string literal");
 					}
+				})
+			}
+
+			if let Some(variant_ident) = default_unit_variant {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::std::default::Default for #type_id {
+						#[inline]
+						fn default() -> Self {
+							#type_id::#variant_ident
+						}
+					}
+				})
+			}
+
+			if options.hash {
+				if has_capture {
+					return error!("the `hash` option is incompatible with capturing variants");
+				}
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::std::borrow::Borrow<::iref::Iri> for #type_id {
+						#[inline]
+						fn borrow(&self) -> &::iref::Iri {
+							<&::iref::Iri as From<&#type_id>>::from(self)
+						}
+					}
+
+					#[automatically_derived]
+					impl ::std::hash::Hash for #type_id {
+						fn hash<H: ::std::hash::Hasher>(&self, __iref_enum_state: &mut H) {
+							let __iref_enum_iri = <&::iref::Iri as From<&#type_id>>::from(self);
+							if __iref_enum_is_comparable(__iref_enum_iri) {
+								::std::hash::Hash::hash(__iref_enum_iri, __iref_enum_state)
+							} else {
+								::std::hash::Hash::hash(__iref_enum_iri.as_str(), __iref_enum_state)
+							}
+						}
+					}
+				})
+			}
+
+			if options.url {
+				if has_capture {
+					return error!("the `url` option is incompatible with capturing variants");
+				}
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl<'a> ::std::convert::TryFrom<&'a #type_id> for ::url::Url {
+						type Error = ::url::ParseError;
+
+						#[inline]
+						fn try_from(__iref_enum_vocab: &'a #type_id) -> ::std::result::Result<::url::Url, ::url::ParseError> {
+							::url::Url::parse(<&::iref::Iri as From<&#type_id>>::from(__iref_enum_vocab).as_str())
+						}
+					}
+
+					#[automatically_derived]
+					impl ::std::convert::TryFrom<#type_id> for ::url::Url {
+						type Error = ::url::ParseError;
+
+						#[inline]
+						fn try_from(__iref_enum_vocab: #type_id) -> ::std::result::Result<::url::Url, ::url::ParseError> {
+							<::url::Url as ::std::convert::TryFrom<&#type_id>>::try_from(&__iref_enum_vocab)
+						}
+					}
+				})
+			}
+
+			if options.from_str {
+				// Whitespace is not allowed in IRIs, so the strict mode only
+				// has to skip the trimming to reject it.
+				let value = if options.from_str_strict {
+					quote! { __iref_enum_s }
 				} else {
-					return error!("expected a string literal");
+					quote! { __iref_enum_s.trim() }
+				};
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::std::str::FromStr for #type_id {
+						type Err = ();
+
+						fn from_str(__iref_enum_s: &str) -> ::std::result::Result<Self, ()> {
+							let __iref_enum_iri = ::iref::Iri::new(#value).map_err(|_| ())?;
+							<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri)#unit_error
+						}
+					}
+				})
+			}
+
+			if options.try_from_iri_ref {
+				// Relative references are resolved against the namespace,
+				// if any.
+				let relative = match &options.namespace {
+					Some(namespace) => {
+						let namespace = namespace.as_str();
+						quote! {
+							None => {
+								let __iref_enum_iri = __iref_enum_iri_ref.resolved(::iref_enum::iri!(#namespace));
+								<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri.as_iri())#unit_error
+							}
+						}
+					}
+					None => quote! { None => Err(()) },
+				};
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl<'a> ::std::convert::TryFrom<&'a ::iref::IriRef> for #type_id {
+						type Error = ();
+
+						fn try_from(__iref_enum_iri_ref: &'a ::iref::IriRef) -> ::std::result::Result<Self, ()> {
+							match __iref_enum_iri_ref.as_iri() {
+								Some(__iref_enum_iri) => <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri)#unit_error,
+								#relative
+							}
+						}
+					}
+				})
+			}
+
+			if options.owned {
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::std::convert::TryFrom<::iref::IriBuf> for #type_id {
+						type Error = ();
+
+						#[inline]
+						fn try_from(__iref_enum_iri: ::iref::IriBuf) -> ::std::result::Result<Self, ()> {
+							<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri.as_iri())#unit_error
+						}
+					}
+				});
+
+				if options.parse_only {
+					// Only the conversions from IRIs are generated.
+				} else if has_capture {
+					output.extend(quote! {
+						#[automatically_derived]
+						impl ::std::convert::TryFrom<#type_id> for ::iref::IriBuf {
+							type Error = ::iref::InvalidIri<String>;
+
+							#[inline]
+							fn try_from(__iref_enum_vocab: #type_id) -> ::std::result::Result<::iref::IriBuf, ::iref::InvalidIri<String>> {
+								<::iref::IriBuf as ::std::convert::TryFrom<&#type_id>>::try_from(&__iref_enum_vocab)
+							}
+						}
+					})
+				} else {
+					output.extend(quote! {
+						#[automatically_derived]
+						impl From<#type_id> for ::iref::IriBuf {
+							#[inline]
+							fn from(__iref_enum_vocab: #type_id) -> ::iref::IriBuf {
+								__iref_enum_vocab.iri().to_owned()
+							}
+						}
+					})
 				}
 			}
-			Ok(None) => (),
-			Err(tokens) => return tokens,
-		}
-	}
 
-	match ast.data {
-		syn::Data::Enum(e) => {
-			let type_id = ast.ident;
-			let mut try_from = proc_macro2::TokenStream::new();
-			let mut try_from_default = quote! { Err(()) };
-			let mut into = proc_macro2::TokenStream::new();
+			if options.serde {
+				if has_capture {
+					return error!("the `serde` option is incompatible with capturing variants");
+				}
+
+				// Variants are (de)serialized as plain strings, so that the
+				// enum can be used as a map key.
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::serde::Serialize for #type_id {
+						#[inline]
+						fn serialize<S: ::serde::Serializer>(&self, __iref_enum_serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+							let __iref_enum_iri = <&::iref::Iri as From<&#type_id>>::from(self);
+							__iref_enum_serializer.serialize_str(__iref_enum_iri.as_str())
+						}
+					}
+
+					#[automatically_derived]
+					impl<'de> ::serde::Deserialize<'de> for #type_id {
+						fn deserialize<D: ::serde::Deserializer<'de>>(__iref_enum_deserializer: D) -> ::std::result::Result<Self, D::Error> {
+							struct __IrefEnumVisitor;
+
+							impl<'de> ::serde::de::Visitor<'de> for __IrefEnumVisitor {
+								type Value = #type_id;
 
-			for variant in e.variants {
-				let variant_ident = variant.ident;
-				let mut variant_iri: Option<IriBuf> = None;
+								fn expecting(&self, __iref_enum_f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+									__iref_enum_f.write_str(concat!("an IRI of `", stringify!(#type_id), "`"))
+								}
 
-				for attr in variant.attrs {
-					match filter_attribute(attr, "iri") {
-						Ok(Some(tokens)) => match string_literal(tokens) {
-							Ok(str) => {
-								if let Ok(iri) = expand_iri(str.as_str(), &prefixes) {
-									variant_iri = Some(iri)
-								} else {
-									return error!(
-										"invalid IRI `{}` for variant `{}`",
-										str, variant_ident
-									);
+								fn visit_str<E: ::serde::de::Error>(self, __iref_enum_value: &str) -> ::std::result::Result<#type_id, E> {
+									let __iref_enum_invalid = || E::invalid_value(::serde::de::Unexpected::Str(__iref_enum_value), &self);
+									let __iref_enum_iri = ::iref::Iri::new(__iref_enum_value).map_err(|_| __iref_enum_invalid())?;
+									<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).map_err(|_| __iref_enum_invalid())
 								}
 							}
-							Err(_) => return error!("malformed `iri` attribute"),
-						},
-						Ok(None) => (),
-						Err(tokens) => return tokens,
+
+							__iref_enum_deserializer.deserialize_str(__IrefEnumVisitor)
+						}
 					}
+				})
+			}
+
+			if options.sophia {
+				if has_capture {
+					return error!("the `sophia` option is incompatible with capturing variants");
 				}
 
-				match variant.fields {
-					syn::Fields::Unit => {
-						if let Some(iri) = variant_iri {
-							let iri = iri.as_str();
+				output.extend(quote! {
+					#[automatically_derived]
+					impl ::sophia_api::term::Term for #type_id {
+						type BorrowTerm<'x> = &'x Self;
 
-							try_from.extend(quote! {
-								_ if iri == static_iref::iri!(#iri) => Ok(#type_id::#variant_ident),
-							});
+						#[inline]
+						fn kind(&self) -> ::sophia_api::term::TermKind {
+							::sophia_api::term::TermKind::Iri
+						}
 
-							into.extend(quote! {
-								#type_id::#variant_ident => static_iref::iri!(#iri),
-							});
-						} else {
-							return error!("missing IRI for enum variant `{}`", variant_ident);
+						#[inline]
+						fn iri(&self) -> Option<::sophia_api::term::IriRef<::sophia_api::MownStr<'_>>> {
+							let __iref_enum_iri = <&::iref::Iri as From<&#type_id>>::from(self);
+							Some(::sophia_api::term::IriRef::new_unchecked(
+								::sophia_api::MownStr::from_ref(__iref_enum_iri.as_str())
+							))
+						}
+
+						#[inline]
+						fn borrow_term(&self) -> Self::BorrowTerm<'_> {
+							self
 						}
 					}
-					syn::Fields::Named(_) => {
-						return error!("variants with named fields are unsupported")
+				})
+			}
+
+			let match_macro =
+				quote::format_ident!("{}_match", RenameRule::Snake.apply(&type_id.to_string()));
+
+			// The error type must be visible outside of the block.
+			let mut from_bytes_error = proc_macro2::TokenStream::new();
+			if options.from_bytes {
+				let error_type_id = quote::format_ident!("{}FromBytesError", type_id);
+				let doc = format!(
+					"Error returned by the `TryFrom<&[u8]>` implementation of [`{}`].",
+					type_id
+				);
+				let unknown = format!("unknown `{}` IRI", type_id);
+				from_bytes_error = quote! {
+					#[doc = #doc]
+					#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+					#[allow(dead_code)]
+					#vis enum #error_type_id {
+						/// The bytes are not valid UTF-8.
+						InvalidUtf8,
+
+						/// The bytes are not a valid IRI.
+						InvalidIri,
+
+						/// The IRI is not the IRI of a term.
+						Unknown,
 					}
-					syn::Fields::Unnamed(fields) => {
-						if fields.unnamed.len() == 1 {
-							let field = fields.unnamed.into_iter().next().unwrap();
-							let ty = field.ty;
 
-							try_from_default = quote! {
-								match #ty::try_from(iri) {
-									Ok(value) => Ok(#type_id::#variant_ident(value)),
-									Err(_) => {
-										#try_from_default
-									}
-								}
-							};
+					#[automatically_derived]
+					impl ::std::fmt::Display for #error_type_id {
+						fn fmt(&self, __iref_enum_f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+							__iref_enum_f.write_str(match self {
+								Self::InvalidUtf8 => "invalid UTF-8",
+								Self::InvalidIri => "invalid IRI",
+								Self::Unknown => #unknown,
+							})
+						}
+					}
 
-							into.extend(quote! {
-								#type_id::#variant_ident(v) => v.into(),
-							});
-						} else {
-							return error!(
-								"variants with named more than one field are unsupported"
-							);
+					#[automatically_derived]
+					impl ::std::error::Error for #error_type_id {}
+				};
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl<'a> ::std::convert::TryFrom<&'a [u8]> for #type_id {
+						type Error = #error_type_id;
+
+						fn try_from(__iref_enum_bytes: &'a [u8]) -> ::std::result::Result<Self, #error_type_id> {
+							let __iref_enum_s = ::std::str::from_utf8(__iref_enum_bytes).map_err(|_| #error_type_id::InvalidUtf8)?;
+							let __iref_enum_iri = ::iref::Iri::new(__iref_enum_s).map_err(|_| #error_type_id::InvalidIri)?;
+							<#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri).map_err(|_| #error_type_id::Unknown)
 						}
 					}
-				}
+				})
 			}
 
-			let output = quote! {
-				impl<'a> ::std::convert::TryFrom<&'a ::iref::Iri> for #type_id {
-					type Error = ();
+			// The classification enum must also be visible outside of the
+			// block.
+			let classification_type_id = quote::format_ident!("{}Classification", type_id);
+			let mut classification = proc_macro2::TokenStream::new();
+			if !namespace_variants.is_empty() {
+				// The longest namespace is tried first.
+				namespace_variants.sort_by_key(|(iri, _)| std::cmp::Reverse(iri.len()));
+				let namespace_iris = namespace_variants.iter().map(|(iri, _)| iri);
+				let namespace_idents = namespace_variants.iter().map(|(_, ident)| ident);
 
-					#[inline]
-					fn try_from(iri: &'a ::iref::Iri) -> ::std::result::Result<#type_id, ()> {
-						match iri {
-							#try_from
-							_ => #try_from_default
+				let doc = format!("Classification of an IRI by [`{}::classify`].", type_id);
+				classification = quote! {
+					#[doc = #doc]
+					#[allow(dead_code)]
+					#vis enum #classification_type_id {
+						/// The IRI is the IRI of a term.
+						Term(#type_id),
+
+						/// The IRI falls under the namespace of a prefix-only
+						/// variant, without being the IRI of a term.
+						Namespace(#type_id),
+
+						/// The IRI is unrelated to the vocabulary.
+						Unknown,
+					}
+				};
+
+				output.extend(quote! {
+					#[automatically_derived]
+					impl #type_id {
+						/// Classifies the given IRI as the IRI of a term, an IRI
+						/// falling under the namespace of a prefix-only variant
+						/// (the longest), or an unknown IRI.
+						#[must_use]
+						#vis fn classify(__iref_enum_iri: &::iref::Iri) -> #classification_type_id {
+							if let Ok(__iref_enum_term) = <#type_id as ::std::convert::TryFrom<&::iref::Iri>>::try_from(__iref_enum_iri) {
+								return #classification_type_id::Term(__iref_enum_term);
+							}
+
+							#(
+								if __iref_enum_iri.as_str().starts_with(#namespace_iris) {
+									return #classification_type_id::Namespace(#type_id::#namespace_idents);
+								}
+							)*
+
+							#classification_type_id::Unknown
 						}
 					}
+				})
+			}
+
+			// Prefix enum and namespace types are named after this.
+			let prefix_map_name = options.prefix_map_name.as_ref().unwrap_or(&type_id);
+
+			// The prefix enum must be visible outside of the `const _` block.
+			// Companion types may be left unused when the enum is not public.
+			let mut prefix_enum = proc_macro2::TokenStream::new();
+			if !output_prefixes.is_empty() {
+				let prefix_type_id = options
+					.prefix_enum
+					.clone()
+					.unwrap_or_else(|| quote::format_ident!("{}Prefix", prefix_map_name));
+				let mut prefix_variants = Vec::new();
+				for (prefix, iri) in &output_prefixes {
+					let name = RenameRule::Pascal.apply(&prefix.replace(['-', '.'], "_"));
+					match syn::parse_str::<syn::Ident>(&name) {
+						Ok(ident) => prefix_variants.push((ident, prefix.as_str(), iri.as_str())),
+						Err(_) => return error!("prefix `{}` is not a valid variant name", prefix),
+					}
+				}
+				if !classification.is_empty() && prefix_type_id == classification_type_id {
+					return error!(
+						"prefix enum `{}` collides with the classification enum",
+						prefix_type_id
+					);
 				}
 
-				impl<'a, 'i> From<&'a #type_id> for &'i ::iref::Iri {
-					#[inline]
-					fn from(vocab: &'a #type_id) -> &'i ::iref::Iri {
-						match vocab {
-							#into
+				prefix_variants.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+				for pair in prefix_variants.windows(2) {
+					if pair[0].0 == pair[1].0 {
+						return error!(
+							"prefixes `{}` and `{}` have the same variant name `{}`",
+							pair[0].1, pair[1].1, pair[0].0
+						);
+					}
+				}
+
+				let doc = format!("Prefixes declared by [`{}`].", type_id);
+				let idents: Vec<_> = prefix_variants.iter().map(|(ident, ..)| ident).collect();
+				let names: Vec<_> = prefix_variants.iter().map(|(_, name, _)| name).collect();
+				let iris: Vec<_> = prefix_variants.iter().map(|(.., iri)| iri).collect();
+				prefix_enum = quote! {
+					#[doc = #doc]
+					#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+					#[allow(dead_code)]
+					#vis enum #prefix_type_id {
+						#(#idents),*
+					}
+
+					#[automatically_derived]
+					impl #prefix_type_id {
+						/// Returns the name of this prefix.
+						#vis fn name(&self) -> &'static str {
+							match self {
+								#(Self::#idents => #names),*
+							}
+						}
+
+						/// Returns the base IRI of this prefix.
+						#vis fn as_iri(&self) -> &'static ::iref::Iri {
+							match self {
+								#(Self::#idents => ::iref_enum::iri!(#iris)),*
+							}
 						}
 					}
 				}
+			}
 
-				impl<'i> From<#type_id> for &'i ::iref::Iri {
-					#[inline]
-					fn from(vocab: #type_id) -> &'i ::iref::Iri {
-						<&::iref::Iri as From<&#type_id>>::from(&vocab)
+			// Namespace types must also be visible outside of the block.
+			let mut namespace_types = proc_macro2::TokenStream::new();
+			if options.namespace_enum {
+				let prefix_type_id = options
+					.prefix_enum
+					.clone()
+					.unwrap_or_else(|| quote::format_ident!("{}Prefix", prefix_map_name));
+				let mut namespaces: Vec<(&str, Vec<&syn::Ident>)> = Vec::new();
+				for (prefix, variant_ident) in &namespace_members {
+					match namespaces.iter_mut().find(|(p, _)| p == prefix) {
+						Some((_, members)) => members.push(variant_ident),
+						None => namespaces.push((prefix, vec![variant_ident])),
 					}
 				}
 
-				impl<'a, 'i> From<&'a #type_id> for &'i ::iref::IriRef {
-					#[inline]
-					fn from(vocab: &'a #type_id) -> &'i ::iref::IriRef {
-						<&::iref::Iri as From<&#type_id>>::from(vocab).as_iri_ref()
+				for (prefix, members) in namespaces {
+					let name = RenameRule::Pascal.apply(&prefix.replace(['-', '.'], "_"));
+					let namespace_type_id =
+						match syn::parse_str::<syn::Ident>(&format!("{}{}", prefix_map_name, name))
+						{
+							Ok(ident) => ident,
+							Err(_) => {
+								return error!(
+									"prefix `{}` is not a valid type name suffix",
+									prefix
+								)
+							}
+						};
+					if namespace_type_id == prefix_type_id {
+						return error!(
+							"namespace type `{}` of prefix `{}` collides with the prefix enum",
+							namespace_type_id, prefix
+						);
+					}
+
+					if !classification.is_empty() && namespace_type_id == classification_type_id {
+						return error!(
+							"namespace type `{}` of prefix `{}` collides with the classification enum",
+							namespace_type_id, prefix
+						);
 					}
+
+					let doc = format!(
+						"Unit variants of [`{}`] under the `{}` prefix.",
+						type_id, prefix
+					);
+					let member_docs = members.iter().map(|member| {
+						let doc = format!("[`{}::{}`].", type_id, member);
+						variant_doc(&doc, &variant_docs[&member.to_string()])
+					});
+					namespace_types.extend(quote! {
+						#[doc = #doc]
+						#[allow(dead_code)]
+						#vis struct #namespace_type_id;
+
+						#[automatically_derived]
+						#[allow(deprecated, non_upper_case_globals)]
+						impl #namespace_type_id {
+							#(
+								#member_docs
+								#vis const #members: #type_id = #type_id::#members;
+							)*
+						}
+					})
 				}
+			}
 
-				impl<'i> From<#type_id> for &'i ::iref::IriRef {
-					#[inline]
-					fn from(vocab: #type_id) -> &'i ::iref::IriRef {
-						<&::iref::Iri as From<#type_id>>::from(vocab).as_iri_ref()
+			// Variants may be marked `#[deprecated]`.
+			quote! {
+				/// Matches a value of the enum, binding the field of wrapped
+				/// variants.
+				#[allow(unused_macros)]
+				macro_rules! #match_macro {
+					($value:expr, { $($variant:ident $(($binding:pat))? => $arm:expr),* $(,)? }) => {
+						match $value {
+							$(#type_id::$variant $(($binding))? => $arm),*
+						}
+					};
+				}
+
+				#prefix_enum
+				#namespace_types
+				#classification
+				#from_bytes_error
+
+				#[allow(deprecated)]
+				const _: () = {
+					// Tells the compiler to expand the derive again when
+					// those variables or files change.
+					#(const _: &str = ::core::env!(#env_vars);)*
+					#(const _: &[u8] = ::core::include_bytes!(#prefix_files);)*
+
+					#output
+				};
+			}
+			.into()
+		}
+		_ => {
+			error!("only enums are handled by IriEnum")
+		}
+	}
+}
+
+/// Derives the `IriRef` conversions of an enum type declared with the
+/// `relative` option.
+///
+/// Every variant must be a unit variant with an IRI reference, taken as is.
+fn derive_relative(type_id: &syn::Ident, vis: &syn::Visibility, e: &syn::DataEnum) -> TokenStream {
+	let mut idents = Vec::new();
+	let mut iri_refs: Vec<String> = Vec::new();
+	for variant in &e.variants {
+		let variant_ident = &variant.ident;
+		let variant_options = match VariantOptions::from_attributes(&variant.attrs) {
+			Ok(variant_options) => variant_options,
+			Err(tokens) => return tokens,
+		};
+
+		if !matches!(variant.fields, syn::Fields::Unit) {
+			return error!(
+				"variant `{}` of a relative vocabulary must be a unit variant",
+				variant_ident
+			);
+		}
+
+		let iri_ref = match variant_options.iri {
+			Some(iri_ref) => match IriRefBuf::new(iri_ref) {
+				Ok(iri_ref) => iri_ref.into_string(),
+				Err(e) => {
+					return error!(
+						"invalid IRI reference `{}` for variant `{}`",
+						e.0, variant_ident
+					)
+				}
+			},
+			None => return error!("missing IRI reference for variant `{}`", variant_ident),
+		};
+
+		if let Some(i) = iri_refs.iter().position(|other| *other == iri_ref) {
+			return error!(
+				"variants `{}` and `{}` have the same IRI reference `{}`",
+				idents[i], variant_ident, iri_ref
+			);
+		}
+
+		idents.push(variant_ident.clone());
+		iri_refs.push(iri_ref);
+	}
+
+	// Variants may be marked `#[deprecated]`.
+	quote! {
+		#[allow(deprecated)]
+		const _: () = {
+			#[automatically_derived]
+			impl<'a> ::std::convert::TryFrom<&'a ::iref::IriRef> for #type_id {
+				type Error = ();
+
+				#[inline]
+				fn try_from(__iref_enum_iri_ref: &'a ::iref::IriRef) -> ::std::result::Result<#type_id, ()> {
+					match __iref_enum_iri_ref.as_str() {
+						#(#iri_refs => Ok(#type_id::#idents),)*
+						_ => Err(()),
 					}
 				}
+			}
 
-				impl AsRef<iref::Iri> for #type_id {
-					#[inline]
-					fn as_ref(&self) -> &::iref::Iri {
-						<&::iref::Iri as From<&#type_id>>::from(self)
+			#[automatically_derived]
+			impl<'a> From<&'a #type_id> for &'static ::iref::IriRef {
+				#[inline]
+				fn from(__iref_enum_vocab: &'a #type_id) -> &'static ::iref::IriRef {
+					__iref_enum_vocab.iri_ref()
+				}
+			}
+
+			#[automatically_derived]
+			impl From<#type_id> for &'static ::iref::IriRef {
+				#[inline]
+				fn from(__iref_enum_vocab: #type_id) -> &'static ::iref::IriRef {
+					__iref_enum_vocab.iri_ref()
+				}
+			}
+
+			#[automatically_derived]
+			impl #type_id {
+				/// Returns the IRI reference of this term.
+				#vis const fn iri_ref(&self) -> &'static ::iref::IriRef {
+					match self {
+						#(#type_id::#idents => unsafe { ::iref::IriRef::new_unchecked(#iri_refs) },)*
 					}
 				}
+			}
+		};
+	}
+	.into()
+}
 
-				impl AsRef<iref::IriRef> for #type_id {
-					#[inline]
-					fn as_ref(&self) -> &::iref::IriRef {
-						<&::iref::IriRef as From<&#type_id>>::from(self)
+/// Body of a `vocabulary!` macro call.
+struct Vocabulary {
+	attrs: Vec<syn::Attribute>,
+	vis: syn::Visibility,
+	ident: syn::Ident,
+	prefixes: Vec<(syn::LitStr, syn::LitStr)>,
+	variants: Vec<VocabularyVariant>,
+}
+
+/// Variant declared in a `vocabulary!` macro call.
+struct VocabularyVariant {
+	attrs: Vec<syn::Attribute>,
+	ident: syn::Ident,
+	field: Option<syn::Type>,
+	iri: Option<syn::LitStr>,
+}
+
+impl Parse for Vocabulary {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let vis = input.parse()?;
+		if input.peek(syn::Token![enum]) {
+			input.parse::<syn::Token![enum]>()?;
+		}
+		let ident = input.parse()?;
+
+		let content;
+		syn::braced!(content in input);
+
+		let mut prefixes = Vec::new();
+		let mut variants = Vec::new();
+		while !content.is_empty() {
+			if content.peek(syn::LitStr) {
+				let prefix = content.parse()?;
+				content.parse::<syn::Token![=>]>()?;
+				prefixes.push((prefix, content.parse()?));
+			} else {
+				let attrs = content.call(syn::Attribute::parse_outer)?;
+				let ident = content.parse()?;
+
+				let field = if content.peek(syn::token::Paren) {
+					let field;
+					syn::parenthesized!(field in content);
+					Some(field.parse()?)
+				} else {
+					None
+				};
+
+				let iri = if content.peek(syn::Token![=>]) {
+					content.parse::<syn::Token![=>]>()?;
+					Some(content.parse()?)
+				} else {
+					None
+				};
+
+				variants.push(VocabularyVariant {
+					attrs,
+					ident,
+					field,
+					iri,
+				});
+			}
+
+			if !content.is_empty() {
+				content.parse::<syn::Token![;]>()?;
+			}
+		}
+
+		Ok(Self {
+			attrs,
+			vis,
+			ident,
+			prefixes,
+			variants,
+		})
+	}
+}
+
+/// Replaces the `feature = "..."` options of the `iri` attributes of a
+/// `vocabulary!` variant with `cfg` attributes, gating the variant.
+///
+/// The derive macro only sees the variants enabled by `cfg`, so the generated
+/// code is gated identically.
+fn feature_gates(attrs: &[syn::Attribute]) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+	let mut result = Vec::new();
+	for attr in attrs {
+		if !attr.path.is_ident("iri") {
+			result.push(quote! { #attr });
+			continue;
+		}
+
+		let mut features: Vec<syn::LitStr> = Vec::new();
+		let args = attr.parse_args_with(|input: ParseStream| {
+			let mut args = Vec::new();
+			while !input.is_empty() {
+				if input.peek(syn::Ident)
+					&& input.peek2(syn::Token![=])
+					&& input.fork().parse::<syn::Ident>()? == "feature"
+				{
+					input.parse::<syn::Ident>()?;
+					input.parse::<syn::Token![=]>()?;
+					features.push(input.parse()?);
+				} else {
+					let mut arg = proc_macro2::TokenStream::new();
+					while !input.is_empty() && !input.peek(syn::Token![,]) {
+						arg.extend(std::iter::once(input.parse::<TokenTree>()?))
 					}
+					args.push(arg)
+				}
+
+				if !input.is_empty() {
+					input.parse::<syn::Token![,]>()?;
 				}
+			}
+
+			Ok(args)
+		})?;
+
+		result.extend(
+			features
+				.iter()
+				.map(|feature| quote! { #[cfg(feature = #feature)] }),
+		);
+		if !args.is_empty() {
+			result.push(quote! { #[iri(#(#args),*)] })
+		}
+	}
+
+	Ok(result)
+}
+
+/// Builds an `&'static Iri` from a string literal, checked at compile time.
+///
+/// This is the macro used by the generated code, so that vocabularies do not
+/// need their own `static-iref` dependency. The IRI is validated with the
+/// version of `iref` this crate depends on, but the expansion names the
+/// `iref` crate of the calling crate (`::iref::Iri`), which must then be a
+/// compatible `iref` 3 version.
+///
+/// ```rust
+/// let iri: &'static iref::Iri = iref_enum::iri!("https://schema.org/name");
+/// assert_eq!(iri, "https://schema.org/name")
+/// ```
+#[proc_macro]
+pub fn iri(input: TokenStream) -> TokenStream {
+	let lit = match syn::parse::<syn::LitStr>(input) {
+		Ok(lit) => lit,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	match IriBuf::new(lit.value()) {
+		Ok(iri) => {
+			let value = iri.as_str();
+			// SAFETY (of the expansion): the literal was just validated as
+			// an IRI, and `iref` 3 versions share the same IRI syntax.
+			quote! {
+				unsafe { ::iref::Iri::new_unchecked(#value) }
+			}
+			.into()
+		}
+		Err(e) => syn::Error::new(lit.span(), format!("invalid IRI `{}`", e.0))
+			.to_compile_error()
+			.into(),
+	}
+}
+
+/// Declares an enum type and derives `IriEnum` for it.
+///
+/// Prefixes are declared with `"prefix" => "iri"` and variants with
+/// `Variant => "iri"`, or `Variant(Type)` for wrapped variants, separated by
+/// semicolons:
+///
+/// ```rust
+/// iref_enum::vocabulary! {
+///   #[derive(PartialEq, Debug)]
+///   pub Vocab {
+///     "schema" => "https://schema.org/";
+///     Name => "schema:name";
+///     Page(u32) => "schema:page/";
+///   }
+/// }
+///
+/// assert_eq!(Vocab::try_from(static_iref::iri!("https://schema.org/page/4")), Ok(Vocab::Page(4)))
+/// ```
+#[proc_macro]
+pub fn vocabulary(input: TokenStream) -> TokenStream {
+	let Vocabulary {
+		attrs,
+		vis,
+		ident,
+		prefixes,
+		variants,
+	} = match syn::parse(input) {
+		Ok(vocabulary) => vocabulary,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	let prefixes = prefixes
+		.iter()
+		.map(|(prefix, iri)| quote! { #[iri_prefix(#prefix = #iri)] });
+
+	let mut tokens = Vec::new();
+	for variant in &variants {
+		let attrs = match feature_gates(&variant.attrs) {
+			Ok(attrs) => attrs,
+			Err(e) => return e.to_compile_error().into(),
+		};
+		let ident = &variant.ident;
+		let field = variant.field.as_ref().map(|ty| quote! { (#ty) });
+		let iri = variant.iri.as_ref().map(|iri| quote! { #[iri(#iri)] });
+		tokens.push(quote! { #(#attrs)* #iri #ident #field })
+	}
+	let variants = tokens;
+
+	quote! {
+		#(#attrs)*
+		#[derive(::iref_enum::IriEnum)]
+		#(#prefixes)*
+		#vis enum #ident {
+			#(#variants),*
+		}
+	}
+	.into()
+}
+
+/// `file = "path"` argument of an `iri_prefix` attribute, referring to a
+/// Turtle file declaring prefixes.
+struct PrefixFile(syn::LitStr);
+
+impl Parse for PrefixFile {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let id: syn::Ident = input.parse()?;
+		if id != "file" {
+			return Err(syn::Error::new(id.span(), "expected `file`"));
+		}
+		input.parse::<syn::Token![=]>()?;
+		Ok(Self(input.parse()?))
+	}
+}
+
+/// Parses the prefix declarations of a Turtle document, either `@prefix`
+/// or SPARQL-style `PREFIX` declarations, ignoring comments.
+///
+/// On error, returns the line number along with the error message.
+fn turtle_prefixes(content: &str) -> Result<Vec<(String, String)>, (usize, String)> {
+	let mut declarations = Vec::new();
+	let mut rest = content;
+	let line = |rest: &str| content[..content.len() - rest.len()].matches('\n').count() + 1;
+
+	loop {
+		// Skip whitespaces and comments.
+		loop {
+			rest = rest.trim_start();
+			match rest.strip_prefix('#') {
+				Some(comment) => rest = comment.split_once('\n').map_or("", |(_, r)| r),
+				None => break,
+			}
+		}
+
+		if rest.is_empty() {
+			break Ok(declarations);
+		}
+
+		let (keyword, after) = rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()));
+		let turtle = match keyword {
+			"@prefix" => true,
+			_ if keyword.eq_ignore_ascii_case("prefix") => false,
+			_ => {
+				return Err((
+					line(rest),
+					format!("expected a prefix declaration, found `{}`", keyword),
+				))
+			}
+		};
+		rest = after.trim_start();
+
+		let Some((name, after)) = rest.split_once(':') else {
+			return Err((line(rest), "expected a prefix name".to_owned()));
+		};
+		if name.contains(char::is_whitespace) {
+			return Err((line(rest), format!("invalid prefix name `{}`", name)));
+		}
+		rest = after.trim_start();
+
+		let Some((iri, after)) = rest.strip_prefix('<').and_then(|r| r.split_once('>')) else {
+			return Err((line(rest), "expected an IRI between `<` and `>`".to_owned()));
+		};
+		declarations.push((name.to_owned(), iri.to_owned()));
+		rest = after.trim_start();
+
+		if turtle {
+			match rest.strip_prefix('.') {
+				Some(after) => rest = after,
+				None => return Err((line(rest), "expected `.`".to_owned())),
+			}
+		}
+	}
+}
+
+/// `use = path` argument of an `iri_prefix` attribute, referring to a shared
+/// prefix set.
+struct PrefixSetUse(syn::Path);
+
+impl Parse for PrefixSetUse {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		input.parse::<syn::Token![use]>()?;
+		input.parse::<syn::Token![=]>()?;
+		Ok(Self(input.parse()?))
+	}
+}
+
+/// Prefix declarations of a shared prefix set.
+struct PrefixSetEntries(Vec<(syn::LitStr, proc_macro2::TokenStream)>);
+
+impl Parse for PrefixSetEntries {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut entries = Vec::new();
+		while !input.is_empty() {
+			let prefix = if input.peek(syn::LitStr) {
+				input.parse()?
+			} else {
+				let ident = input.call(syn::Ident::parse_any)?;
+				syn::LitStr::new(&ident.to_string(), ident.span())
 			};
+			input.parse::<syn::Token![=]>()?;
+
+			let mut value = proc_macro2::TokenStream::new();
+			while !input.is_empty() && !input.peek(syn::Token![;]) {
+				value.extend([input.parse::<TokenTree>()?])
+			}
+
+			if value.is_empty() {
+				return Err(input.error("expected a prefix IRI"));
+			}
 
-			output.into()
+			entries.push((prefix, value));
+			if !input.is_empty() {
+				input.parse::<syn::Token![;]>()?;
+			}
 		}
-		_ => {
-			error!("only enums are handled by IriEnum")
+
+		Ok(Self(entries))
+	}
+}
+
+/// Body of an `iri_prefixes!` macro call.
+struct PrefixSets(Vec<(syn::Ident, PrefixSetEntries)>);
+
+impl Parse for PrefixSets {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut sets = Vec::new();
+		while !input.is_empty() {
+			let ident = input.parse()?;
+			let content;
+			syn::braced!(content in input);
+			sets.push((ident, content.parse()?));
 		}
+
+		Ok(Self(sets))
 	}
 }
 
-fn string_literal(tokens: proc_macro2::TokenStream) -> Result<String, &'static str> {
-	if let Some(token) = tokens.into_iter().next() {
-		string_literal_token(token)
-	} else {
-		Err("expected one string parameter")
+/// Declares prefix sets that can be shared by several enum types.
+///
+/// Each set is a declarative macro, referred to by the
+/// `#[iri_prefix(use = set)]` attribute. It follows the textual scoping of
+/// `macro_rules!` macros.
+///
+/// ```rust
+/// # use iref_enum::IriEnum;
+/// iref_enum::iri_prefixes! {
+///   schema_prefixes {
+///     schema = "https://schema.org/";
+///     "foaf" = "http://xmlns.com/foaf/0.1/";
+///   }
+/// }
+///
+/// #[derive(IriEnum)]
+/// #[iri_prefix(use = schema_prefixes)]
+/// pub enum People {
+///   #[iri("foaf:Person")] Person
+/// }
+///
+/// #[derive(IriEnum)]
+/// #[iri_prefix(use = schema_prefixes)]
+/// pub enum Properties {
+///   #[iri("schema:name")] Name
+/// }
+/// ```
+#[proc_macro]
+pub fn iri_prefixes(input: TokenStream) -> TokenStream {
+	let PrefixSets(sets) = match syn::parse(input) {
+		Ok(sets) => sets,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	let sets = sets.into_iter().map(|(ident, PrefixSetEntries(entries))| {
+		let entries = entries
+			.iter()
+			.map(|(prefix, value)| quote! { #prefix = #value; });
+		quote! {
+			#[allow(unused_macros)]
+			macro_rules! #ident {
+				(@iri_enum_derive $($input:tt)*) => {
+					::iref_enum::__iri_enum_derive_with! { { #(#entries)* } $($input)* }
+				};
+			}
+		}
+	});
+
+	quote! { #(#sets)* }.into()
+}
+
+/// Input of the `__iri_enum_derive_with` macro.
+struct DeriveWith(PrefixSetEntries, syn::DeriveInput);
+
+impl Parse for DeriveWith {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let content;
+		syn::braced!(content in input);
+		Ok(Self(content.parse()?, input.parse()?))
+	}
+}
+
+/// Derives `IriEnum` for the given enum type, with the given prefixes
+/// declared.
+///
+/// This is called by the macros declared with `iri_prefixes!`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __iri_enum_derive_with(input: TokenStream) -> TokenStream {
+	let DeriveWith(PrefixSetEntries(entries), mut ast) = match syn::parse(input) {
+		Ok(input) => input,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	// The prefixes of the set come first, so that the enum can override them.
+	for (i, (prefix, value)) in entries.into_iter().enumerate() {
+		ast.attrs
+			.insert(i, syn::parse_quote! { #[iri_prefix(#prefix = #value)] })
 	}
+
+	derive(ast)
 }
 
 fn string_literal_token(token: proc_macro2::TokenTree) -> Result<String, &'static str> {
@@ -325,3 +5349,128 @@ fn string_literal_token(token: proc_macro2::TokenTree) -> Result<String, &'stati
 		Err("expected string literal")
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use proptest::prelude::*;
+
+	/// Strategy generating prefix tables, including empty prefix names and
+	/// invalid prefix IRIs.
+	fn prefixes() -> impl Strategy<Value = HashMap<String, IriBuf>> {
+		proptest::collection::hash_map(
+			"[a-z]{0,3}",
+			"(https?://[a-z]{1,5}(\\.[a-z]{1,3})?/([a-z]{0,3}[/#])?|urn:[a-z]{1,3}:)",
+			0..4,
+		)
+		.prop_map(|map| {
+			map.into_iter()
+				.map(|(name, iri)| (name, IriBuf::new(iri).unwrap()))
+				.collect()
+		})
+	}
+
+	/// Returns the resulting IRI as a string, since comparing IRIs panics on
+	/// percent-encoded sequences that are not valid UTF-8.
+	fn as_string<E>(result: Result<IriBuf, E>) -> Option<String> {
+		result.ok().map(IriBuf::into_string)
+	}
+
+	/// Strategy generating values with colons, slashes and characters
+	/// invalid in IRIs.
+	fn value() -> impl Strategy<Value = String> {
+		"[a-z:/#?% ]{0,12}"
+	}
+
+	#[test]
+	fn turtle_prefixes_parsing() {
+		let content = "# Prefixes.\n@prefix schema: <https://schema.org/> .\nPREFIX ex: <https://ex.org/#> # Comment.\n@prefix : <https://default.org/>.";
+		assert_eq!(
+			turtle_prefixes(content),
+			Ok(vec![
+				("schema".to_owned(), "https://schema.org/".to_owned()),
+				("ex".to_owned(), "https://ex.org/#".to_owned()),
+				(String::new(), "https://default.org/".to_owned()),
+			])
+		);
+		assert_eq!(
+			turtle_prefixes("@prefix a: <https://a.org/> .\n@prefix b: <https://b.org/>\n"),
+			Err((3, "expected `.`".to_owned()))
+		);
+		assert_eq!(
+			turtle_prefixes("\n\n<https://a.org/s> <https://a.org/p> <https://a.org/o> ."),
+			Err((
+				3,
+				"expected a prefix declaration, found `<https://a.org/s>`".to_owned()
+			))
+		)
+	}
+
+	#[test]
+	fn common_prefixes() {
+		assert_eq!(common_prefix(&[]), "");
+		assert_eq!(common_prefix(&["https://ex.org/a"]), "https://ex.org/a");
+		assert_eq!(
+			common_prefix(&["https://ex.org/", "https://ex.org/b", "https://ex.org/bc"]),
+			"https://ex.org/"
+		);
+		assert_eq!(
+			common_prefix(&["https://ex.org/", "http://ex.org/"]),
+			"http"
+		);
+		// The prefix ends on a character boundary.
+		assert_eq!(
+			common_prefix(&["https://ex.org/\u{e9}", "https://ex.org/\u{e8}"]),
+			"https://ex.org/"
+		)
+	}
+
+	proptest! {
+		#[test]
+		fn expand_never_panics(prefixes in prefixes(), value in value()) {
+			let _ = expand_iri(&value, &prefixes);
+		}
+
+		#[test]
+		fn expand_declared_prefix(prefixes in prefixes(), value in value()) {
+			let result = expand_iri(&value, &prefixes);
+			match split_compact_iri(&value).and_then(|(prefix, suffix)| {
+				prefixes.get(prefix).map(|base_iri| (base_iri, suffix))
+			}) {
+				// A declared prefix is always expanded, colons in the suffix
+				// included, and never falls back to the raw value.
+				Some((base_iri, suffix)) => {
+					let expected = base_iri.as_str().to_owned() + suffix;
+					prop_assert_eq!(as_string(result), as_string(IriBuf::new(expected)))
+				}
+				// Otherwise the value is taken as is.
+				None => prop_assert_eq!(as_string(result), as_string(IriBuf::new(value.clone()))),
+			}
+		}
+
+		#[test]
+		fn expand_without_colon_fails(prefixes in prefixes(), value in "[a-z/#?]{0,12}") {
+			// Expanded or not, an IRI needs a scheme.
+			prop_assert!(expand_iri(&value, &prefixes).is_err())
+		}
+
+		#[test]
+		fn empty_prefix_is_never_used(
+			mut prefixes in prefixes(),
+			suffix in value(),
+		) {
+			prefixes.insert(String::new(), IriBuf::new("https://ex.org/".to_owned()).unwrap());
+			let value = format!(":{}", suffix);
+			prop_assert!(expand_iri(&value, &prefixes).is_err())
+		}
+
+		#[test]
+		fn authority_is_never_expanded(prefixes in prefixes(), name in "[a-z]{1,3}", rest in value()) {
+			let value = format!("{}://{}", name, rest);
+			prop_assert_eq!(
+				as_string(expand_iri(&value, &prefixes)),
+				as_string(IriBuf::new(value.clone()))
+			)
+		}
+	}
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "idn")]
+use iref_enum::IriEnum;
+use static_iref::iri;
+
+#[test]
+fn unicode_host() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_idn)]
+	pub enum Vocab {
+		#[iri("https://xn--caf-dma.example/name")]
+		Name,
+		#[iri("https://bücher.example/title")]
+		Title,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://café.example/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://xn--caf-dma.example/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://xn--bcher-kva.example/title")),
+		Ok(Vocab::Title)
+	);
+
+	// Conversions into IRIs return the declared IRI.
+	assert_eq!(Vocab::Title.iri(), iri!("https://bücher.example/title"));
+	assert_eq!(Vocab::try_from(iri!("https://cafe.example/name")), Err(()))
+}
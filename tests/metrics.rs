@@ -0,0 +1,27 @@
+#![cfg(feature = "metrics")]
+use iref_enum::IriEnum;
+use static_iref::iri;
+
+#[test]
+fn match_counts() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("schema:page/")]
+		Page(u32),
+	}
+
+	assert_eq!(Vocab::match_counts(), [(Vocab::Name, 0), (Vocab::Knows, 0)]);
+
+	Vocab::try_from(iri!("https://schema.org/knows")).unwrap();
+	Vocab::try_from(iri!("https://schema.org/./knows")).unwrap();
+	Vocab::try_from(iri!("https://schema.org/name")).unwrap();
+	Vocab::try_from(iri!("https://schema.org/page/1")).unwrap();
+	Vocab::try_from(iri!("https://schema.org/other")).unwrap_err();
+
+	assert_eq!(Vocab::match_counts(), [(Vocab::Name, 1), (Vocab::Knows, 2)])
+}
@@ -0,0 +1,51 @@
+use static_iref::iri;
+
+mod vocab {
+	mod private {
+		use iref_enum::IriEnum;
+
+		// Local item shadowing the `iref` crate name.
+		#[allow(dead_code)]
+		mod iref {}
+
+		#[derive(IriEnum, PartialEq, Debug)]
+		#[iri_prefix("schema" = "https://schema.org/")]
+		pub enum Vocab {
+			#[iri("schema:name")]
+			Name,
+			Other(super::other::OtherVocab),
+		}
+	}
+
+	mod other {
+		use iref_enum::IriEnum;
+
+		#[derive(IriEnum, PartialEq, Debug)]
+		#[iri_prefix("schema" = "https://schema.org/")]
+		pub enum OtherVocab {
+			#[iri("schema:Text")]
+			Text,
+		}
+	}
+
+	pub use other::OtherVocab;
+	pub use private::Vocab;
+}
+
+use vocab::{OtherVocab, Vocab};
+
+#[test]
+fn try_from_reexported() {
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/Text")),
+		Ok(Vocab::Other(OtherVocab::Text))
+	);
+	assert_eq!(
+		<&iref::Iri>::from(Vocab::Name),
+		iri!("https://schema.org/name")
+	)
+}
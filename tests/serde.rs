@@ -0,0 +1,39 @@
+#![cfg(feature = "serde")]
+use iref_enum::IriEnum;
+use std::collections::HashMap;
+
+#[derive(IriEnum, PartialEq, Eq, Hash, Debug)]
+#[iri(serde)]
+#[iri_prefix("schema" = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri("schema:knows")]
+	Knows,
+}
+
+#[test]
+fn serde() {
+	assert_eq!(
+		serde_json::to_string(&Vocab::Name).unwrap(),
+		r#""https://schema.org/name""#
+	);
+	assert_eq!(
+		serde_json::from_str::<Vocab>(r#""https://schema.org/knows""#).unwrap(),
+		Vocab::Knows
+	);
+	assert!(serde_json::from_str::<Vocab>(r#""https://schema.org/other""#).is_err());
+	assert!(serde_json::from_str::<Vocab>(r#""not an iri""#).is_err())
+}
+
+#[test]
+fn map_key() {
+	let map = HashMap::from([(Vocab::Name, 1), (Vocab::Knows, 2)]);
+	let json = serde_json::to_string(&map).unwrap();
+	assert!(json.contains(r#""https://schema.org/name":1"#));
+	assert!(json.contains(r#""https://schema.org/knows":2"#));
+	assert_eq!(
+		serde_json::from_str::<HashMap<Vocab, i32>>(&json).unwrap(),
+		map
+	)
+}
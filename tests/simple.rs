@@ -56,3 +56,2756 @@ fn try_from_with_parameter() {
 	);
 	assert_eq!(Vocab::try_from(iri!("https://schema.org/other")), Err(()))
 }
+
+#[test]
+fn group() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("schema-person" = "https://schema.org/Person/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema-person:knows")]
+		Knows,
+		#[iri("https://example.org/other")]
+		Other,
+		Sub(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum OtherVocab {
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert_eq!(Vocab::Name.group(), "schema");
+	assert_eq!(Vocab::Knows.group(), "schema-person");
+	assert_eq!(Vocab::Other.group(), "");
+	assert_eq!(Vocab::Sub(OtherVocab::Text).group(), "schema")
+}
+
+#[test]
+fn group_aliases() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("s" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("s:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::matching_prefix(iri!("https://schema.org/name")),
+		Some("s")
+	);
+	assert_eq!(Vocab::Name.group(), "s");
+	assert_eq!(Vocab::Knows.group(), "s");
+	assert_eq!(Vocab::Name.to_compact(), Some("s:name".to_string()))
+}
+
+#[test]
+fn suffix_with_colons() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("xsd" = "http://www.w3.org/2001/XMLSchema#")]
+	pub enum Vocab {
+		#[iri("schema:xsd:dateTime")]
+		DateTime,
+		#[iri("schema:a:b:c")]
+		Abc,
+		#[iri("urn:isbn:123")]
+		Isbn,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/xsd:dateTime")),
+		Ok(Vocab::DateTime)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/a:b:c")),
+		Ok(Vocab::Abc)
+	);
+	assert_eq!(Vocab::try_from(iri!("urn:isbn:123")), Ok(Vocab::Isbn));
+	assert_eq!(
+		<&iref::Iri>::from(Vocab::DateTime),
+		iri!("https://schema.org/xsd:dateTime")
+	);
+	assert_eq!(<&iref::Iri>::from(Vocab::Isbn), iri!("urn:isbn:123"))
+}
+
+#[test]
+fn try_from_suffix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("name")]
+		Name,
+		#[iri("https://schema.org/knows")]
+		Knows,
+		#[iri("https://example.org/other")]
+		Other,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::try_from_suffix("name"), Some(Vocab::Name));
+	assert_eq!(Vocab::try_from_suffix("knows"), Some(Vocab::Knows));
+	assert_eq!(Vocab::try_from_suffix("other"), None);
+	assert_eq!(Vocab::try_from_suffix("https://example.org/other"), None)
+}
+
+#[test]
+fn capture() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://ex.org/")]
+	pub enum Vocab {
+		#[iri("ex:name")]
+		Name,
+		#[iri("ex:page/")]
+		Page(u32),
+		#[iri("ex:book/")]
+		Book(String),
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/book/123/page/4")),
+		Ok(Vocab::Book("123/page/4".to_string()))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/book/")),
+		Ok(Vocab::Book(String::new()))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/page/4")),
+		Ok(Vocab::Page(4))
+	);
+	assert_eq!(Vocab::try_from(iri!("https://ex.org/page/four")), Err(()));
+	assert_eq!(Vocab::try_from(iri!("https://ex.org/other")), Err(()));
+	assert_eq!(
+		iref::IriBuf::try_from(&Vocab::Book("123".to_string())).unwrap(),
+		iri!("https://ex.org/book/123")
+	);
+	assert_eq!(Vocab::Page(4).group(), "ex")
+}
+
+#[test]
+fn capture_case_insensitive() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(case_insensitive)]
+	pub enum Vocab {
+		#[iri("https://ex.org/Book/")]
+		Book(String),
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/Book/ABC")),
+		Ok(Vocab::Book("ABC".to_string()))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("HTTPS://EX.ORG/book/Abc")),
+		Ok(Vocab::Book("Abc".to_string()))
+	);
+	assert_eq!(
+		iref::IriBuf::try_from(&Vocab::Book("ABC".to_string())).unwrap(),
+		iri!("https://ex.org/Book/ABC")
+	);
+}
+
+#[test]
+fn from_iri_const() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	const NAME: Option<Vocab> = Vocab::from_iri_const("https://schema.org/name");
+	const KNOWS: Option<Vocab> = Vocab::from_iri_const("https://schema.org/knows");
+	const OTHER: Option<Vocab> = Vocab::from_iri_const("https://schema.org/other");
+
+	assert_eq!(NAME, Some(Vocab::Name));
+	assert_eq!(KNOWS, Some(Vocab::Knows));
+	assert_eq!(OTHER, None)
+}
+
+#[test]
+fn matching_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("schema-person" = "https://schema.org/Person/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	assert_eq!(
+		Vocab::matching_prefix(iri!("https://schema.org/knows")),
+		Some("schema")
+	);
+	assert_eq!(
+		Vocab::matching_prefix(iri!("https://schema.org/Person/knows")),
+		Some("schema-person")
+	);
+	assert_eq!(
+		Vocab::matching_prefix(iri!("https://example.org/knows")),
+		None
+	)
+}
+
+#[test]
+fn static_iri_ref() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	// With `iref` 3, `&'static IriRef` is the by-value IRI reference type.
+	struct Term {
+		iri_ref: &'static iref::IriRef,
+	}
+
+	let term = Term {
+		iri_ref: Vocab::Name.into(),
+	};
+
+	assert_eq!(term.iri_ref.as_str(), "https://schema.org/name")
+}
+
+#[test]
+fn rename_all() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://ex.org/", rename_all = "camelCase")]
+	pub enum Camel {
+		DateTime,
+		HTTPServer,
+		#[iri("other")]
+		Overridden,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://ex.org/", rename_all = "snake_case")]
+	pub enum Snake {
+		DateTime,
+		HTTPServer,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://ex.org/", rename_all = "kebab-case")]
+	pub enum Kebab {
+		DateTime,
+		HTTPServer,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://ex.org/", rename_all = "PascalCase")]
+	pub enum Pascal {
+		DateTime,
+		HTTPServer,
+	}
+
+	assert_eq!(Camel::try_from_suffix("dateTime"), Some(Camel::DateTime));
+	assert_eq!(
+		Camel::try_from_suffix("httpServer"),
+		Some(Camel::HTTPServer)
+	);
+	assert_eq!(Camel::try_from_suffix("other"), Some(Camel::Overridden));
+	assert_eq!(Camel::try_from_suffix("overridden"), None);
+	assert_eq!(Snake::try_from_suffix("date_time"), Some(Snake::DateTime));
+	assert_eq!(
+		Snake::try_from_suffix("http_server"),
+		Some(Snake::HTTPServer)
+	);
+	assert_eq!(Kebab::try_from_suffix("date-time"), Some(Kebab::DateTime));
+	assert_eq!(
+		Kebab::try_from_suffix("http-server"),
+		Some(Kebab::HTTPServer)
+	);
+	assert_eq!(Pascal::try_from_suffix("DateTime"), Some(Pascal::DateTime));
+	assert_eq!(
+		Pascal::try_from_suffix("HttpServer"),
+		Some(Pascal::HTTPServer)
+	);
+	assert_eq!(
+		Camel::try_from(iri!("https://ex.org/dateTime")),
+		Ok(Camel::DateTime)
+	)
+}
+
+#[test]
+fn no_expand() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("custom" = "https://ex.org/")]
+	pub enum Vocab {
+		#[iri("custom:thing")]
+		Expanded,
+		#[iri("custom:thing", expand = false)]
+		Literal,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/thing")),
+		Ok(Vocab::Expanded)
+	);
+	assert_eq!(Vocab::try_from(iri!("custom:thing")), Ok(Vocab::Literal))
+}
+
+#[test]
+fn require_known_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(require_known_prefix)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("https://example.org/knows")]
+		Knows,
+		#[iri("urn:example:thing", expand = false)]
+		Thing,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(Vocab::try_from(iri!("urn:example:thing")), Ok(Vocab::Thing))
+}
+
+#[test]
+fn str_eq() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:page/")]
+		Page(u32),
+		Other(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum OtherVocab {
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert!(Vocab::Name == "https://schema.org/name");
+	assert!(Vocab::Name != "https://schema.org/knows");
+	assert!(Vocab::Page(4) == "https://schema.org/page/4");
+	assert!(Vocab::Page(4) != "https://schema.org/page/5");
+	assert!(Vocab::Other(OtherVocab::Text) == *"https://schema.org/Text");
+	assert!(Vocab::Other(OtherVocab::Text) != "https://schema.org/name")
+}
+
+#[test]
+fn parse_all() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	let (terms, unmatched) = Vocab::parse_all(&[
+		"https://schema.org/knows",
+		"https://schema.org/other",
+		"https://schema.org/name",
+		"not an IRI",
+	]);
+
+	assert_eq!(terms, vec![Vocab::Knows, Vocab::Name]);
+	assert_eq!(unmatched, vec!["https://schema.org/other", "not an IRI"])
+}
+
+#[test]
+fn matches_any() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::matches_any(&[
+			iri!("http://schema.org/knows"),
+			iri!("https://schema.org/knows"),
+			iri!("https://schema.org/name"),
+		]),
+		Some(Vocab::Knows)
+	);
+	assert_eq!(Vocab::matches_any(&[iri!("http://schema.org/name")]), None);
+	assert_eq!(Vocab::matches_any(&[]), None)
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name", deprecated = "use `Knows` instead")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("schema:friend", deprecated = "use `Knows` instead")]
+		#[deprecated]
+		Friend,
+	}
+
+	assert!(Vocab::Name.is_deprecated());
+	assert!(!Vocab::Knows.is_deprecated());
+	assert_eq!(Vocab::Knows.deprecation_note(), None);
+	assert_eq!(
+		Vocab::Friend.deprecation_note(),
+		Some("use `Knows` instead")
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/friend")),
+		Ok(Vocab::Friend)
+	)
+}
+
+#[test]
+fn is_wrapped() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		Other(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum OtherVocab {
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert!(!Vocab::Name.is_wrapped());
+	assert!(Vocab::Other(OtherVocab::Text).is_wrapped());
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(
+		Vocab::Other(OtherVocab::Text).iri(),
+		iri!("https://schema.org/Text")
+	)
+}
+
+#[test]
+fn env_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("repo" = env!("CARGO_PKG_REPOSITORY"))]
+	pub enum Vocab {
+		#[iri("repo:/issues")]
+		Issues,
+	}
+
+	assert_eq!(
+		Vocab::Issues.iri(),
+		concat!(env!("CARGO_PKG_REPOSITORY"), "/issues")
+	);
+	assert_eq!(
+		Vocab::try_from(iri!(
+			"https://github.com/timothee-haudebourg/iref-enum/issues"
+		)),
+		Ok(Vocab::Issues)
+	)
+}
+
+#[test]
+fn validate() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("repo" = env!("CARGO_PKG_REPOSITORY"))]
+	#[iri_prefix("source" = env!("CARGO_PKG_REPOSITORY"))]
+	pub enum Colliding {
+		#[iri("repo:/issues")]
+		Issues,
+		#[iri("source:/issues")]
+		SourceIssues,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("repo" = env!("CARGO_PKG_REPOSITORY"))]
+	pub enum Valid {
+		#[iri("repo:/issues")]
+		Issues,
+		#[iri("repo:/pulls")]
+		Pulls,
+	}
+
+	assert_eq!(
+		Colliding::validate(),
+		Err("variants `Issues` and `SourceIssues` have the same IRI `https://github.com/timothee-haudebourg/iref-enum/issues`".to_string())
+	);
+	assert_eq!(Valid::validate(), Ok(()))
+}
+
+#[test]
+fn match_macro() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum SchemaVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		Other(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum OtherVocab {
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	let describe = |term: SchemaVocab| {
+		schema_vocab_match!(term, {
+			Name => "name".to_string(),
+			Knows => "knows".to_string(),
+			Other(inner) => format!("other: {}", inner.iri()),
+		})
+	};
+
+	assert_eq!(describe(SchemaVocab::Name), "name");
+	assert_eq!(describe(SchemaVocab::Knows), "knows");
+	assert_eq!(
+		describe(SchemaVocab::Other(OtherVocab::Text)),
+		"other: https://schema.org/Text"
+	)
+}
+
+#[test]
+fn normalize_default_port() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_default_port)]
+	pub enum Vocab {
+		#[iri("https://example.org/name")]
+		Name,
+		#[iri("http://example.org:80/knows")]
+		Knows,
+		#[iri("https://example.org:8443/other")]
+		Other,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org:443/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("http://example.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org:8443/other")),
+		Ok(Vocab::Other)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://example.org/other")), Err(()));
+	assert_eq!(Vocab::Knows.iri(), iri!("http://example.org:80/knows"))
+}
+
+#[test]
+fn capture_default_port() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_default_port)]
+	pub enum Vocab {
+		#[iri("https://ex.org:443/book/")]
+		Book(String),
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org/book/abc")),
+		Ok(Vocab::Book("abc".to_string()))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://ex.org:443/book/abc")),
+		Ok(Vocab::Book("abc".to_string()))
+	);
+}
+
+#[test]
+fn iris_of() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::iris_of(&[Vocab::Knows, Vocab::Name, Vocab::Knows]),
+		[
+			"https://schema.org/knows",
+			"https://schema.org/name",
+			"https://schema.org/knows"
+		]
+	);
+	assert!(Vocab::iris_of(&[]).is_empty())
+}
+
+#[test]
+fn transparent() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri(transparent)]
+		Other(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum OtherVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/Text")), Err(()));
+	assert_eq!(
+		Vocab::Other(OtherVocab::Text).iri(),
+		iri!("https://schema.org/Text")
+	);
+	assert_eq!(
+		Vocab::Other(OtherVocab::Name).iri(),
+		iri!("https://schema.org/name")
+	)
+}
+
+#[test]
+fn default_variant() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri(default)]
+		Other(iref::IriBuf),
+	}
+
+	let other = Vocab::try_from(iri!("https://example.org/other")).unwrap();
+	assert_eq!(
+		other,
+		Vocab::Other(iri!("https://example.org/other").to_owned())
+	);
+
+	let iri: &iref::Iri = other.as_ref();
+	assert_eq!(iri, iri!("https://example.org/other"));
+	assert_eq!(other.iri(), iri!("https://example.org/other"));
+	assert_eq!(
+		Vocab::iris_of(&[Vocab::Name, other]),
+		["https://schema.org/name", "https://example.org/other"]
+	);
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"))
+}
+
+#[test]
+fn base_of() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://example.org/ontology/")]
+	pub enum Vocab {
+		#[iri("ex:Person")]
+		Person,
+		#[iri(base_of = Person, suffix = "/Adult")]
+		Adult,
+		#[iri(base_of = Adult, suffix = "#senior")]
+		Senior,
+	}
+
+	assert_eq!(
+		Vocab::Adult.iri(),
+		iri!("https://example.org/ontology/Person/Adult")
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/ontology/Person/Adult#senior")),
+		Ok(Vocab::Senior)
+	)
+}
+
+#[test]
+fn from_compact() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("ex:knows")]
+		Knows,
+	}
+
+	let context = [
+		("s", iri!("https://schema.org/")),
+		("ex", iri!("https://schema.org/")),
+	];
+
+	assert_eq!(Vocab::from_compact("s:name", &context), Some(Vocab::Name));
+	assert_eq!(Vocab::from_compact("ex:name", &context), Some(Vocab::Name));
+	assert_eq!(Vocab::from_compact("ex:knows", &context), None);
+	assert_eq!(
+		Vocab::from_compact("schema:name", &context),
+		Some(Vocab::Name)
+	);
+	assert_eq!(Vocab::from_compact("ex:knows", &[]), Some(Vocab::Knows));
+	assert_eq!(
+		Vocab::from_compact("https://example.org/knows", &context),
+		Some(Vocab::Knows)
+	);
+	assert_eq!(Vocab::from_compact("s:knows", &context), None)
+}
+
+#[test]
+fn equivalent_iri() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("schema:name")]
+		Duplicate,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/./other/../knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/kn%6Fws")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/%aa")), Err(()));
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	)
+}
+
+#[test]
+fn vocabulary() {
+	iref_enum::vocabulary! {
+		#[derive(PartialEq, Debug)]
+		pub Vocab {
+			"schema" => "https://schema.org/";
+			Name => "schema:name";
+			#[iri(deprecated = "use `Name`")]
+			Title => "schema:title";
+			Page(u32) => "schema:page/";
+			Other(OtherVocab);
+		}
+	}
+
+	iref_enum::vocabulary! {
+		#[derive(PartialEq, Debug)]
+		pub enum OtherVocab {
+			Text => "https://schema.org/Text"
+		}
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert!(Vocab::Title.is_deprecated());
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/page/4")),
+		Ok(Vocab::Page(4))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/Text")),
+		Ok(Vocab::Other(OtherVocab::Text))
+	);
+	assert_eq!(Vocab::Name.group(), "schema")
+}
+
+#[test]
+fn from_any() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	struct Property(iref::IriBuf);
+
+	impl AsRef<iref::Iri> for Property {
+		fn as_ref(&self) -> &iref::Iri {
+			&self.0
+		}
+	}
+
+	let name = Property(iri!("https://schema.org/name").to_owned());
+	let other = Property(iri!("https://schema.org/Text").to_owned());
+	assert_eq!(Vocab::from_any(&name), Some(Vocab::Name));
+	assert_eq!(Vocab::from_any(&other), None);
+	assert_eq!(Vocab::from_any(&name.0), Some(Vocab::Name))
+}
+
+#[test]
+fn expr() {
+	const SCHEMA: &str = "https://schema.org/";
+	const NAME: &str = const_format::concatcp!(SCHEMA, "name");
+
+	mod consts {
+		pub const KNOWS: &str = "https://schema.org/knows";
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri(expr = NAME)]
+		Name,
+		#[iri(expr = consts::KNOWS)]
+		Knows,
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/./knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::Knows, "https://schema.org/knows");
+	assert_eq!(Vocab::Knows.group(), "schema");
+	assert_eq!(
+		Vocab::from_iri_const("https://schema.org/name"),
+		Some(Vocab::Name)
+	);
+	assert_eq!(Vocab::validate(), Ok(()))
+}
+
+#[test]
+fn expr_hygiene() {
+	const IRIS: &str = "https://schema.org/name";
+	const DATA: &str = "https://schema.org/knows";
+	#[allow(non_upper_case_globals)]
+	const eq: &str = "https://schema.org/Text";
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(inline_data)]
+	pub enum Vocab {
+		#[iri(expr = IRIS)]
+		Name,
+		#[iri(expr = DATA)]
+		Knows,
+		#[iri(expr = eq)]
+		Text,
+		#[iri("https://schema.org/Person")]
+		Person,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/Person")),
+		Ok(Vocab::Person)
+	);
+	assert_eq!(
+		Vocab::from_iri_const("https://schema.org/Text"),
+		Some(Vocab::Text)
+	);
+	assert_eq!(Vocab::validate(), Ok(()))
+}
+
+#[test]
+fn from_iri_cached() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:page/")]
+		Page(u32),
+	}
+
+	let mut cache = std::collections::HashMap::new();
+	assert_eq!(
+		Vocab::from_iri_cached(iri!("https://schema.org/page/4"), &mut cache),
+		Some(&Vocab::Page(4))
+	);
+	assert_eq!(cache.len(), 1);
+
+	// Second lookup: cache hit, even if the cached term differs from what
+	// `try_from` would return.
+	cache.insert(iri!("https://schema.org/page/4").to_owned(), Vocab::Page(5));
+	assert_eq!(
+		Vocab::from_iri_cached(iri!("https://schema.org/page/4"), &mut cache),
+		Some(&Vocab::Page(5))
+	);
+
+	assert_eq!(
+		Vocab::from_iri_cached(iri!("https://schema.org/Text"), &mut cache),
+		None
+	);
+	assert_eq!(
+		Vocab::from_iri_cached(iri!("https://schema.org/%aa"), &mut cache),
+		None
+	);
+	assert_eq!(cache.len(), 1)
+}
+
+#[test]
+fn optional_field() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:Text")]
+		Text(Option<TextVocab>),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum TextVocab {
+		#[iri("schema:URL")]
+		Url,
+		#[iri("schema:CssSelectorType")]
+		CssSelector,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/Text")),
+		Ok(Vocab::Text(None))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/URL")),
+		Ok(Vocab::Text(Some(TextVocab::Url)))
+	);
+	assert_eq!(Vocab::Text(None).iri(), iri!("https://schema.org/Text"));
+	assert_eq!(
+		Vocab::Text(Some(TextVocab::CssSelector)).iri(),
+		iri!("https://schema.org/CssSelectorType")
+	);
+	assert_eq!(Vocab::Text(None), "https://schema.org/Text");
+	assert_eq!(Vocab::Text(None).group(), "schema")
+}
+
+#[test]
+fn longest_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(longest_prefix)]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum Vocab {
+		#[iri("ex:a")]
+		A,
+		#[iri("ex:a/b")]
+		B,
+		#[iri("ex:c")]
+		C,
+	}
+
+	assert_eq!(Vocab::try_from(iri!("https://example.org/a")), Ok(Vocab::A));
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a/b/c")),
+		Ok(Vocab::B)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a/other")),
+		Ok(Vocab::A)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a#fragment")),
+		Ok(Vocab::A)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://example.org/abc")), Err(()));
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a/bc")),
+		Ok(Vocab::A)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://example.org/d")), Err(()))
+}
+
+#[allow(non_upper_case_globals)]
+mod hygiene {
+	use iref_enum::IriEnum;
+	use static_iref::iri;
+
+	const iri: &str = "shadowing";
+	const vocab: &str = "shadowing";
+	const value: &str = "shadowing";
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	#[test]
+	fn local_bindings() {
+		let term = iri!("https://schema.org/knows");
+		assert_eq!(Vocab::try_from(term), Ok(Vocab::Knows));
+		assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+		assert_eq!((iri, vocab, value), ("shadowing", "shadowing", "shadowing"))
+	}
+}
+
+#[test]
+fn discriminants() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name = 1,
+		#[iri("schema:knows")]
+		Knows = 20,
+		#[iri("schema:Text")]
+		Text,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::Name.as_code(), 1);
+	assert_eq!(Vocab::Knows.as_code(), 20);
+	assert_eq!(Vocab::Text.as_code(), 21);
+	assert_eq!(Vocab::Knows as u32, 20)
+}
+
+#[test]
+fn to_turtle_term() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("ex:a/b")]
+		Path,
+		#[iri("https://other.org/thing")]
+		Other,
+	}
+
+	assert_eq!(Vocab::Name.to_compact(), Some("schema:name".to_string()));
+	assert_eq!(Vocab::Path.to_compact(), Some("ex:a/b".to_string()));
+	assert_eq!(Vocab::Other.to_compact(), None);
+	assert_eq!(Vocab::Name.to_turtle_term(), "schema:name");
+	assert_eq!(Vocab::Path.to_turtle_term(), "<https://example.org/a/b>");
+	assert_eq!(Vocab::Other.to_turtle_term(), "<https://other.org/thing>")
+}
+
+#[test]
+fn compact_strategy() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(compact_strategy = "longest_prefix")]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("schemaorgpeople" = "https://schema.org/people/")]
+	#[iri_prefix("s" = "https://schema.org/")]
+	pub enum Longest {
+		#[iri("https://schema.org/people/alice")]
+		Alice,
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(compact_strategy = "shortest")]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("schemaorgpeople" = "https://schema.org/people/")]
+	#[iri_prefix("s" = "https://schema.org/")]
+	pub enum Shortest {
+		#[iri("https://schema.org/people/alice")]
+		Alice,
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(compact_strategy = "first_declared")]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("schemaorgpeople" = "https://schema.org/people/")]
+	#[iri_prefix("s" = "https://schema.org/")]
+	pub enum FirstDeclared {
+		#[iri("https://schema.org/people/alice")]
+		Alice,
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	assert_eq!(
+		Longest::Alice.to_compact().as_deref(),
+		Some("schemaorgpeople:alice")
+	);
+	assert_eq!(
+		Shortest::Alice.to_compact().as_deref(),
+		Some("s:people/alice")
+	);
+	assert_eq!(
+		FirstDeclared::Alice.to_compact().as_deref(),
+		Some("schema:people/alice")
+	);
+	assert_eq!(Shortest::Name.to_compact().as_deref(), Some("s:name"));
+	assert_eq!(
+		FirstDeclared::Name.to_compact().as_deref(),
+		Some("schema:name")
+	)
+}
+
+#[test]
+fn normalize_path() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_path, longest_prefix)]
+	pub enum Vocab {
+		#[iri("https://example.org/b/name")]
+		Name,
+		#[iri("https://example.org/c/./knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a/../b/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/c/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/a/../b/name/first")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::Knows.iri(), iri!("https://example.org/c/./knows"))
+}
+
+#[test]
+fn prefix_enum() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("foaf-core" = "http://xmlns.com/foaf/0.1/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("foaf-core:knows")]
+		Knows,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(prefix_enum = "Namespace")]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum OtherVocab {
+		#[iri("ex:thing")]
+		Thing,
+	}
+
+	assert_eq!(VocabPrefix::Schema.as_iri(), iri!("https://schema.org/"));
+	assert_eq!(VocabPrefix::FoafCore.name(), "foaf-core");
+	assert_eq!(
+		VocabPrefix::FoafCore.as_iri(),
+		iri!("http://xmlns.com/foaf/0.1/")
+	);
+	assert_eq!(Namespace::Ex.as_iri(), iri!("https://example.org/"))
+}
+
+#[test]
+fn match_query() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(match_query = "ignore")]
+	pub enum Vocab {
+		#[iri("https://example.org/name")]
+		Name,
+		#[iri("https://example.org/search?q=term#top")]
+		Search,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum FullVocab {
+		#[iri("https://example.org/name")]
+		Name,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/name?lang=en&format=json")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/search#top")),
+		Ok(Vocab::Search)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/name?lang=en#frag")),
+		Err(())
+	);
+	assert_eq!(
+		FullVocab::try_from(iri!("https://example.org/name?lang=en")),
+		Err(())
+	)
+}
+
+#[test]
+fn prefix_sep() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns", sep = "#")]
+	#[iri_prefix("schema" = "https://schema.org", sep = "/")]
+	pub enum Vocab {
+		#[iri("rdf:type")]
+		Type,
+		#[iri("schema:name")]
+		Name,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type")),
+		Ok(Vocab::Type)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::Type.group(), "rdf")
+}
+
+#[test]
+fn iri_by_value() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum Vocab {
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	fn store(iri: &'static iref::Iri) -> [&'static iref::Iri; 2] {
+		[iri, iri]
+	}
+
+	let iri = Vocab::Name.iri();
+	assert_eq!(store(iri), [iri!("https://schema.org/name"); 2])
+}
+
+iref_enum::iri_prefixes! {
+	schema_prefixes {
+		schema = "https://schema.org/";
+		"foaf" = "http://xmlns.com/foaf/0.1/";
+	}
+}
+
+#[test]
+fn shared_prefixes() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix(use = schema_prefixes)]
+	pub enum VocabA {
+		#[iri("schema:name")]
+		Name,
+		#[iri("foaf:knows")]
+		Knows,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix(use = schema_prefixes)]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum VocabB {
+		#[iri("schema:Person")]
+		Person,
+		#[iri("ex:thing")]
+		Thing,
+	}
+
+	assert_eq!(
+		VocabA::try_from(iri!("https://schema.org/name")),
+		Ok(VocabA::Name)
+	);
+	assert_eq!(
+		VocabA::try_from(iri!("http://xmlns.com/foaf/0.1/knows")),
+		Ok(VocabA::Knows)
+	);
+	assert_eq!(
+		VocabB::try_from(iri!("https://schema.org/Person")),
+		Ok(VocabB::Person)
+	);
+	assert_eq!(VocabB::Thing.iri(), iri!("https://example.org/thing"));
+	assert_eq!(VocabA::Knows.group(), "foaf");
+	assert_eq!(vocab_a_match!(VocabA::Name, { Name => 1, Knows => 2 }), 1)
+}
+
+#[test]
+fn match_str() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(match_str, normalize_default_port)]
+	pub enum Vocab {
+		#[iri("https://schema.org/name")]
+		Name,
+		#[iri("https://schema.org/knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org:443/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/./name")), Err(()));
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/kn%6Fws")), Err(()))
+}
+
+#[test]
+fn assert_roundtrip() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_default_port)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("https://schema.org:443/knows")]
+		Knows,
+		#[iri("schema:Text")]
+		Text(Option<TextVocab>),
+		#[iri("schema:page/")]
+		Page(u32),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum TextVocab {
+		#[iri("https://schema.org/URL")]
+		Url,
+	}
+
+	Vocab::assert_roundtrip();
+	TextVocab::assert_roundtrip()
+}
+
+#[test]
+#[should_panic(expected = "variants `Name` and `Label` have the same IRI")]
+fn assert_roundtrip_duplicate() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("https://schema.org/name")]
+		Label,
+	}
+
+	Vocab::assert_roundtrip()
+}
+
+#[test]
+fn catch_variant() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum SchemaVocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum AnyVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("ex:thing")]
+		Thing,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum Vocab {
+		Schema(SchemaVocab),
+		#[iri(catch)]
+		Any(AnyVocab),
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Schema(SchemaVocab::Name))
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.org/thing")),
+		Ok(Vocab::Any(AnyVocab::Thing))
+	);
+	assert_eq!(Vocab::try_from(iri!("https://example.org/other")), Err(()));
+	assert_eq!(
+		Vocab::Any(AnyVocab::Thing).iri(),
+		iri!("https://example.org/thing")
+	)
+}
+
+#[test]
+fn prefix_only() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(longest_prefix)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:", prefix_only)]
+		Schema,
+		#[iri("schema:name")]
+		Name,
+	}
+
+	assert_eq!(Vocab::Schema.base_iri(), Some(iri!("https://schema.org/")));
+	assert_eq!(Vocab::Name.base_iri(), None);
+	assert_eq!(Vocab::Schema.iri(), iri!("https://schema.org/"));
+	assert_eq!(Vocab::Schema.group(), "schema");
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/")), Err(()));
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/knows")), Err(()));
+	assert_eq!(Vocab::from_iri_const("https://schema.org/"), None);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	)
+}
+
+#[test]
+fn hash() {
+	use std::collections::HashSet;
+	use std::hash::{BuildHasher, RandomState};
+
+	#[derive(IriEnum, PartialEq, Eq, Hash, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum DerivedVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	#[derive(IriEnum, PartialEq, Eq, Debug)]
+	#[iri(hash)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum IriVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri(default)]
+		Other(iref::IriBuf),
+	}
+
+	let derived: HashSet<_> = [DerivedVocab::Name, DerivedVocab::Knows, DerivedVocab::Name]
+		.into_iter()
+		.collect();
+	assert_eq!(derived.len(), 2);
+	assert!(derived.contains(&DerivedVocab::Knows));
+
+	let other = IriVocab::try_from(iri!("https://example.org/%aa")).unwrap();
+	let iri_hashed: HashSet<_> = [IriVocab::Name, IriVocab::Knows, IriVocab::Name, other]
+		.into_iter()
+		.collect();
+	assert_eq!(iri_hashed.len(), 3);
+	assert!(iri_hashed.contains(&IriVocab::Knows));
+
+	let state = RandomState::new();
+	assert_eq!(
+		state.hash_one(IriVocab::Name),
+		state.hash_one(iri!("https://schema.org/name"))
+	);
+	assert_eq!(
+		state.hash_one(IriVocab::Other(iri!("https://schema.org/name").to_owned())),
+		state.hash_one(IriVocab::Name)
+	)
+}
+
+#[test]
+fn prefix_file() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix(file = "tests/fixtures/prefixes.ttl")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("rdf:type")]
+		Type,
+		#[iri("foaf:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type")),
+		Ok(Vocab::Type)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::Knows.group(), "foaf");
+	assert!(!Vocab::has_prefix(""));
+	assert_eq!(VocabPrefix::Schema.as_iri(), iri!("https://schema.org/"))
+}
+
+#[test]
+fn iri_consts() {
+	const KNOWS: &str = "https://schema.org/knows";
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:givenName")]
+		GivenName,
+		#[iri(expr = KNOWS)]
+		Knows,
+	}
+
+	fn classify(iri: &str) -> Option<&'static str> {
+		match iri {
+			Vocab::NAME => Some("name"),
+			Vocab::GIVEN_NAME => Some("given name"),
+			Vocab::KNOWS => Some("knows"),
+			_ => None,
+		}
+	}
+
+	assert_eq!(Vocab::NAME, "https://schema.org/name");
+	assert_eq!(classify("https://schema.org/givenName"), Some("given name"));
+	assert_eq!(classify("https://schema.org/knows"), Some("knows"));
+	assert_eq!(classify("https://schema.org/other"), None)
+}
+
+#[test]
+fn secondary_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("sdo" = "https://schema.org/", secondary)]
+	#[iri_prefix("old" = "https://old.example.org/", secondary)]
+	pub enum Vocab {
+		#[iri("sdo:name")]
+		Name,
+		#[iri("old:thing")]
+		Thing,
+	}
+
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::from_compact("sdo:name", &[]), Some(Vocab::Name));
+	assert_eq!(Vocab::from_compact("old:thing", &[]), Some(Vocab::Thing));
+	assert_eq!(Vocab::Name.to_compact().as_deref(), Some("schema:name"));
+	assert_eq!(Vocab::Thing.to_compact(), None);
+	assert_eq!(
+		Vocab::Thing.to_turtle_term(),
+		"<https://old.example.org/thing>"
+	);
+	assert_eq!(Vocab::Thing.group(), "");
+	assert_eq!(
+		Vocab::matching_prefix(iri!("https://old.example.org/other")),
+		None
+	);
+	assert_eq!(VocabPrefix::Schema.name(), "schema")
+}
+
+#[test]
+fn deep_nesting() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+	pub enum Level3 {
+		#[iri("foaf:knows")]
+		Knows,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://example.org/")]
+	pub enum Level2 {
+		#[iri("ex:thing")]
+		Thing,
+		Foaf(Level3),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Level1 {
+		#[iri("schema:name")]
+		Name,
+		Nested(Level2),
+	}
+
+	assert_eq!(
+		Level1::try_from(iri!("https://schema.org/name")),
+		Ok(Level1::Name)
+	);
+	assert_eq!(
+		Level1::try_from(iri!("https://example.org/thing")),
+		Ok(Level1::Nested(Level2::Thing))
+	);
+	assert_eq!(
+		Level1::try_from(iri!("http://xmlns.com/foaf/0.1/knows")),
+		Ok(Level1::Nested(Level2::Foaf(Level3::Knows)))
+	);
+	assert_eq!(
+		Level1::try_from(iri!("http://xmlns.com/foaf/0.1/%6Bnows")),
+		Ok(Level1::Nested(Level2::Foaf(Level3::Knows)))
+	);
+	assert_eq!(
+		Level1::try_from(iri!("http://xmlns.com/foaf/0.1/other")),
+		Err(())
+	);
+	assert_eq!(Level1::try_from(iri!("https://unknown.org/")), Err(()));
+
+	let term = Level1::Nested(Level2::Foaf(Level3::Knows));
+	assert_eq!(term.iri(), iri!("http://xmlns.com/foaf/0.1/knows"));
+	assert_eq!(term, "http://xmlns.com/foaf/0.1/knows");
+	assert!(term.is_wrapped());
+	// The group is computed with the prefixes of the outer enum.
+	assert_eq!(term.group(), "");
+	assert_eq!(Level1::from_compact("ex:thing", &[]), None);
+	assert_eq!(
+		Level1::from_compact("ex:thing", &[("ex", iri!("https://example.org/"))]),
+		Some(Level1::Nested(Level2::Thing))
+	)
+}
+
+#[test]
+fn default_impl() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:Thing", default_variant)]
+		Thing,
+	}
+
+	#[derive(Default)]
+	struct Field {
+		ty: Vocab,
+	}
+
+	assert_eq!(Vocab::default(), Vocab::Thing);
+	assert_eq!(Field::default().ty, Vocab::Thing);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/other")), Err(()))
+}
+
+#[test]
+fn override_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://example.org/")]
+	#[iri_prefix("ex" = "https://example.com/", override)]
+	pub enum Vocab {
+		#[iri("ex:thing")]
+		Thing,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix(use = schema_prefixes)]
+	#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.2/", override)]
+	pub enum SharedVocab {
+		#[iri("foaf:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://example.com/thing")),
+		Ok(Vocab::Thing)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://example.org/thing")), Err(()));
+	assert_eq!(Vocab::Thing.to_compact().as_deref(), Some("ex:thing"));
+	assert_eq!(
+		SharedVocab::try_from(iri!("http://xmlns.com/foaf/0.2/knows")),
+		Ok(SharedVocab::Knows)
+	)
+}
+
+#[test]
+fn static_iri() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	const TABLE: [&iref::Iri; 2] = [Vocab::Name.static_iri(), Vocab::Knows.static_iri()];
+
+	assert_eq!(TABLE[0], iri!("https://schema.org/name"));
+	assert_eq!(TABLE[1], Vocab::Knows.iri())
+}
+
+#[test]
+fn variant_iris() {
+	#[derive(IriEnum, PartialEq, Debug, Clone, Copy)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name = 4,
+		#[iri("schema:knows")]
+		Knows = 2,
+		#[iri("schema:parent")]
+		Parent = 7,
+	}
+
+	const IRIS: [&iref::Iri; Vocab::VARIANTS] = Vocab::VARIANT_IRIS;
+	const PARENT: &iref::Iri = IRIS[Vocab::Parent.discriminant()];
+
+	assert_eq!(Vocab::VARIANTS, 3);
+	assert_eq!(PARENT, iri!("https://schema.org/parent"));
+	for term in [Vocab::Name, Vocab::Knows, Vocab::Parent] {
+		assert_eq!(IRIS[term.discriminant()], term.iri())
+	}
+}
+
+#[test]
+fn case_insensitive() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(case_insensitive)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:givenName")]
+		GivenName,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/NAME")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("HTTPS://Schema.org/Name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/givenname")),
+		Ok(Vocab::GivenName)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/other")), Err(()));
+	assert_eq!(Vocab::GivenName.iri(), "https://schema.org/givenName")
+}
+
+#[test]
+fn iri_macro() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	const NAME: &iref::Iri = iref_enum::iri!("https://schema.org/name");
+	assert_eq!(Vocab::try_from(NAME), Ok(Vocab::Name));
+	assert_eq!(Vocab::Name.iri(), NAME)
+}
+
+#[test]
+fn long_common_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://vocabularies.example.org/people/2024/terms/")]
+	pub enum Vocab {
+		#[iri("ex:")]
+		Terms,
+		#[iri("ex:name")]
+		Name,
+		#[iri("ex:names")]
+		Names,
+		#[iri("ex:caf\u{e9}")]
+		Cafe,
+	}
+
+	const EX: &str = "https://vocabularies.example.org/people/2024/terms/";
+	let try_from =
+		|suffix: &str| Vocab::try_from(iref::Iri::new(&format!("{EX}{suffix}")).unwrap());
+	assert_eq!(try_from(""), Ok(Vocab::Terms));
+	assert_eq!(try_from("name"), Ok(Vocab::Name));
+	assert_eq!(try_from("names"), Ok(Vocab::Names));
+	assert_eq!(try_from("caf\u{e9}"), Ok(Vocab::Cafe));
+	assert_eq!(try_from("./name"), Ok(Vocab::Name));
+	assert_eq!(try_from("other"), Err(()));
+	assert_eq!(
+		Vocab::try_from(iri!("https://vocabularies.example.org/people/")),
+		Err(())
+	)
+}
+
+mod validators {
+	pub fn is_versioned(iri: &str) -> bool {
+		iri.contains("/v1/")
+	}
+}
+
+#[test]
+fn validate_with() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(validate_with = validators::is_versioned)]
+	#[iri_prefix("ex" = "https://example.org/v1/")]
+	pub enum Vocab {
+		#[iri("ex:name")]
+		Name,
+		#[iri("https://example.org/knows")]
+		Knows,
+		#[iri("ex:age")]
+		Age,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(validate_with = validators::is_versioned)]
+	#[iri_prefix("ex" = "https://example.org/v1/")]
+	pub enum Valid {
+		#[iri("ex:name")]
+		Name,
+	}
+
+	assert_eq!(Vocab::validate_iris(), Err(vec![Vocab::Knows]));
+	assert_eq!(Valid::validate_iris(), Ok(()))
+}
+
+#[test]
+fn validate_with_expr() {
+	const NAME: &str = "https://example.org/v1/name";
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(validate_with = validators::is_versioned)]
+	pub enum Vocab {
+		#[iri(expr = NAME)]
+		Name,
+		#[iri("https://example.org/knows")]
+		Knows,
+	}
+
+	assert_eq!(Vocab::validate(), Ok(()));
+	assert_eq!(Vocab::validate_iris(), Err(vec![Vocab::Knows]))
+}
+
+#[test]
+fn by_local_name() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+	#[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns#")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("rdf:type")]
+		Type,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("foaf:knows")]
+		FoafKnows,
+		#[iri("https://example.org/people/")]
+		People,
+	}
+
+	assert_eq!(Vocab::by_local_name("name"), Some(Vocab::Name));
+	assert_eq!(Vocab::by_local_name("type"), Some(Vocab::Type));
+	// Ambiguous local name.
+	assert_eq!(Vocab::by_local_name("knows"), None);
+	assert_eq!(Vocab::by_local_name("people"), None);
+	assert_eq!(Vocab::by_local_name(""), None)
+}
+
+#[test]
+fn namespace_enum() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace_enum)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("foaf:knows")]
+		FoafKnows,
+		#[iri("foaf:", prefix_only)]
+		Foaf,
+		#[iri("https://example.org/other")]
+		Other,
+		Wrapped(OtherVocab),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum OtherVocab {
+		#[iri("https://example.org/thing")]
+		Thing,
+	}
+
+	assert_eq!(VocabSchema::Name, Vocab::Name);
+	assert_eq!(VocabSchema::Knows, Vocab::Knows);
+	assert_eq!(VocabFoaf::FoafKnows, Vocab::FoafKnows);
+	assert_eq!(VocabFoaf::Foaf, Vocab::Foaf);
+	assert_eq!(VocabSchema::Name.iri(), "https://schema.org/name")
+}
+
+#[test]
+fn namespace_enum_aliases() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace_enum)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("s" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("s:knows")]
+		Knows,
+	}
+
+	assert_eq!(VocabS::Name, Vocab::Name);
+	assert_eq!(VocabS::Knows, Vocab::Knows)
+}
+
+#[test]
+fn relative() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(relative)]
+	pub enum Section {
+		#[iri("#introduction")]
+		Introduction,
+		#[iri("#usage")]
+		Usage,
+		#[iri("appendix#glossary")]
+		Glossary,
+	}
+
+	const USAGE: &iref::IriRef = Section::Usage.iri_ref();
+
+	assert_eq!(
+		Section::try_from(iref::IriRef::new("#introduction").unwrap()),
+		Ok(Section::Introduction)
+	);
+	assert_eq!(Section::try_from(USAGE), Ok(Section::Usage));
+	assert_eq!(
+		Section::try_from(iref::IriRef::new("#other").unwrap()),
+		Err(())
+	);
+	let glossary: &iref::IriRef = Section::Glossary.into();
+	assert_eq!(glossary.as_str(), "appendix#glossary");
+	assert_eq!(
+		glossary
+			.resolved(iri!("https://example.org/doc/index"))
+			.as_str(),
+		"https://example.org/doc/appendix#glossary"
+	)
+}
+
+#[test]
+fn feature_gated_variant() {
+	iref_enum::vocabulary! {
+		#[derive(PartialEq, Debug)]
+		pub Vocab {
+			Name => "https://schema.org/name";
+			#[iri(feature = "metrics", deprecated = "use `Name`")]
+			Title => "https://schema.org/title";
+		}
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum AttrVocab {
+		#[iri("https://schema.org/name")]
+		Name,
+		#[cfg(feature = "metrics")]
+		#[iri("https://schema.org/title")]
+		Title,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+
+	#[cfg(feature = "metrics")]
+	{
+		assert_eq!(
+			Vocab::try_from(iri!("https://schema.org/title")),
+			Ok(Vocab::Title)
+		);
+		assert!(Vocab::Title.is_deprecated());
+		assert_eq!(
+			AttrVocab::try_from(iri!("https://schema.org/title")),
+			Ok(AttrVocab::Title)
+		)
+	}
+
+	#[cfg(not(feature = "metrics"))]
+	{
+		assert_eq!(Vocab::try_from(iri!("https://schema.org/title")), Err(()));
+		assert_eq!(
+			AttrVocab::try_from(iri!("https://schema.org/title")),
+			Err(())
+		)
+	}
+}
+
+#[test]
+fn borrow_iri() {
+	use std::collections::HashMap;
+
+	#[derive(IriEnum, PartialEq, Eq, Debug)]
+	#[iri(hash)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri(default)]
+		Other(iref::IriBuf),
+	}
+
+	let other = Vocab::try_from(iri!("https://example.org/other")).unwrap();
+	let map = HashMap::from([(Vocab::Name, 1), (Vocab::Knows, 2), (other, 3)]);
+	assert_eq!(map.get(iri!("https://schema.org/name")), Some(&1));
+	assert_eq!(map.get(iri!("https://schema.org/knows")), Some(&2));
+	assert_eq!(map.get(iri!("https://example.org/other")), Some(&3));
+	assert_eq!(map.get(iri!("https://schema.org/other")), None)
+}
+
+#[test]
+fn from_str() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(from_str)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Lenient {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(try_from_str_strict)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Strict {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	assert_eq!(" https://schema.org/name\n".parse(), Ok(Lenient::Name));
+	assert_eq!("https://schema.org/name".parse(), Ok(Lenient::Name));
+	assert_eq!("https://schema.org/knows".parse::<Lenient>(), Err(()));
+
+	assert_eq!(" https://schema.org/name\n".parse::<Strict>(), Err(()));
+	assert_eq!("https://schema.org/name".parse(), Ok(Strict::Name))
+}
+
+#[test]
+fn variant_prefix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+		#[iri("foaf:knows")]
+		Knows,
+		#[iri_prefix("schema" = "http://schema.org/")]
+		#[iri("schema:legacyName")]
+		LegacyName,
+	}
+
+	assert_eq!(Vocab::Knows.iri(), iri!("http://xmlns.com/foaf/0.1/knows"));
+	assert_eq!(
+		Vocab::LegacyName.iri(),
+		iri!("http://schema.org/legacyName")
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::from_compact("foaf:knows", &[]), None);
+	assert_eq!(Vocab::Knows.to_compact(), None)
+}
+
+#[test]
+fn inline_data() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(inline_data)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("schema:givenName")]
+		GivenName,
+		#[iri("schema:Person")]
+		Person,
+	}
+
+	for term in [Vocab::Name, Vocab::Knows, Vocab::GivenName, Vocab::Person] {
+		assert_eq!(Vocab::try_from(term.iri()), Ok(term))
+	}
+
+	// Equivalent IRIs are still matched.
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/%6Eame")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::try_from(iri!("https://schema.org/parent")), Err(()))
+}
+
+#[test]
+fn classify() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:", prefix_only)]
+		Schema,
+		#[iri("https://schema.org/extensions/", prefix_only)]
+		Extensions,
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:extensions/knows")]
+		Knows,
+	}
+
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/name")),
+		VocabClassification::Term(Vocab::Name)
+	));
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/extensions/knows")),
+		VocabClassification::Term(Vocab::Knows)
+	));
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/givenName")),
+		VocabClassification::Namespace(Vocab::Schema)
+	));
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/extensions/parent")),
+		VocabClassification::Namespace(Vocab::Extensions)
+	));
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/")),
+		VocabClassification::Namespace(Vocab::Schema)
+	));
+	assert!(matches!(
+		Vocab::classify(iri!("https://ex.org/name")),
+		VocabClassification::Unknown
+	))
+}
+
+#[test]
+fn normalize() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_default_port, case_insensitive)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	assert_eq!(
+		Vocab::normalize(iri!("HTTPS://Schema.org:443/NAME")),
+		iri!("https://schema.org/name")
+	);
+	assert_eq!(
+		Vocab::normalize(iri!("https://schema.org/%6Eame")),
+		iri!("https://schema.org/name")
+	);
+	assert_eq!(
+		Vocab::normalize(iri!("https://schema.org:443/knows")),
+		iri!("https://schema.org:443/knows")
+	)
+}
+
+#[test]
+fn variant_docs() {
+	/// Vocabulary with documented terms.
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace_enum)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		/// The name of the item.
+		#[iri("schema:name")]
+		Name,
+
+		/// A person known by this person.
+		///
+		/// See also `Follows`.
+		#[iri("schema:knows")]
+		#[doc = "Not necessarily mutual."]
+		Knows,
+
+		#[iri("schema:follows")]
+		Follows,
+	}
+
+	assert_eq!(Vocab::NAME, "https://schema.org/name");
+	assert_eq!(Vocab::KNOWS, "https://schema.org/knows");
+	assert_eq!(VocabSchema::Knows, Vocab::Knows);
+	assert_eq!(VocabSchema::Follows, Vocab::Follows)
+}
+
+#[test]
+fn schemes() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(schemes("https", "http"))]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("http://xmlns.com/foaf/0.1/knows")]
+		Knows,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(schemes("https"))]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum DefaultVocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri(default)]
+		Other(iref::IriBuf),
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/./name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(iri!("http://xmlns.com/foaf/0.1/knows")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(Vocab::try_from(iri!("ftp://schema.org/name")), Err(()));
+
+	// Other schemes still reach the default variant.
+	assert_eq!(
+		DefaultVocab::try_from(iri!("ftp://schema.org/name")),
+		Ok(DefaultVocab::Other(
+			iri!("ftp://schema.org/name").to_owned()
+		))
+	)
+}
+
+#[test]
+fn parse_only() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(into = false)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(Vocab::KNOWS, "https://schema.org/knows");
+	Vocab::assert_roundtrip()
+}
+
+#[test]
+fn describe() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("https://schema.org/", prefix_only)]
+		Schema,
+		#[iri("https://ex.org/knows")]
+		Knows,
+	}
+
+	let entries: Vec<_> = Vocab::describe().collect();
+	assert_eq!(
+		entries,
+		[
+			(
+				Vocab::Name,
+				iri!("https://schema.org/name"),
+				Some("schema:name".to_owned())
+			),
+			(Vocab::Knows, iri!("https://ex.org/knows"), None)
+		]
+	)
+}
+
+mod restricted {
+	use iref_enum::IriEnum;
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace_enum)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub(crate) enum Vocab {
+		#[iri("https://schema.org/", prefix_only)]
+		Schema,
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub(super) enum Private {
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+}
+
+#[test]
+fn restricted_visibility() {
+	use restricted::{Private, Vocab, VocabClassification, VocabPrefix, VocabSchema};
+
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(Vocab::NAME, "https://schema.org/name");
+	assert_eq!(Vocab::from_iri_const(Vocab::NAME), Some(Vocab::Name));
+	assert_eq!(VocabSchema::Name, Vocab::Name);
+	assert_eq!(VocabPrefix::Schema.name(), "schema");
+	assert!(matches!(
+		Vocab::classify(iri!("https://schema.org/knows")),
+		VocabClassification::Namespace(Vocab::Schema)
+	));
+	assert_eq!(Private::Name.static_iri(), iri!("https://schema.org/name"))
+}
+
+#[test]
+fn prefix_map_name() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace_enum, prefix_map_name = People)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("foaf" = "http://xmlns.com/foaf/0.1/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("foaf:knows")]
+		Knows,
+	}
+
+	assert_eq!(PeopleSchema::Name, Vocab::Name);
+	assert_eq!(PeopleFoaf::Knows, Vocab::Knows);
+	assert_eq!(PeoplePrefix::Foaf.name(), "foaf")
+}
+
+#[test]
+fn from_bytes() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(from_bytes)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	let bytes: &[u8] = b"https://schema.org/name";
+	assert_eq!(Vocab::try_from(bytes), Ok(Vocab::Name));
+	assert_eq!(
+		Vocab::try_from(&b"https://schema.org/\xff"[..]),
+		Err(VocabFromBytesError::InvalidUtf8)
+	);
+	assert_eq!(
+		Vocab::try_from(&b"schema name"[..]),
+		Err(VocabFromBytesError::InvalidIri)
+	);
+	assert_eq!(
+		Vocab::try_from(&b"https://schema.org/knows"[..]),
+		Err(VocabFromBytesError::Unknown)
+	);
+	assert_eq!(
+		VocabFromBytesError::Unknown.to_string(),
+		"unknown `Vocab` IRI"
+	)
+}
+
+#[test]
+fn compare() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(compare = "raw")]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Raw {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(compare = "normalized")]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Normalized {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Default {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	for iri in [
+		static_iref::iri!("https://schema.org/%6Eame"),
+		static_iref::iri!("https://schema.org/./name"),
+	] {
+		assert_eq!(Raw::try_from(iri), Err(()));
+		assert_eq!(Normalized::try_from(iri), Ok(Normalized::Name));
+		assert_eq!(Default::try_from(iri), Ok(Default::Name))
+	}
+
+	let iri = static_iref::iri!("https://schema.org/name");
+	assert_eq!(Raw::try_from(iri), Ok(Raw::Name));
+	assert_eq!(Normalized::try_from(iri), Ok(Normalized::Name))
+}
+
+#[test]
+fn owned() {
+	use iref::IriBuf;
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(owned)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+
+		#[iri(default)]
+		Other(IriBuf),
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(owned)]
+	pub enum Wrapper {
+		#[iri("https://ex.org/book/")]
+		Book(String),
+	}
+
+	let name = IriBuf::new("https://schema.org/name".to_string()).unwrap();
+	let other = IriBuf::new("https://schema.org/knows".to_string()).unwrap();
+	assert_eq!(Vocab::try_from(name.clone()), Ok(Vocab::Name));
+	assert_eq!(
+		Vocab::try_from(other.clone()),
+		Ok(Vocab::Other(other.clone()))
+	);
+	assert_eq!(IriBuf::from(Vocab::Name), name);
+	assert_eq!(IriBuf::from(Vocab::Other(other.clone())), other);
+
+	let book = IriBuf::new("https://ex.org/book/1".to_string()).unwrap();
+	assert_eq!(
+		Wrapper::try_from(book.clone()),
+		Ok(Wrapper::Book("1".to_string()))
+	);
+	assert_eq!(
+		IriBuf::try_from(Wrapper::Book("1".to_string())).unwrap(),
+		book
+	)
+}
+
+#[test]
+fn try_from_iri_ref() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(try_from_iri_ref)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(namespace = "https://schema.org/", try_from_iri_ref)]
+	pub enum Namespaced {
+		#[iri("name")]
+		Name,
+	}
+
+	let absolute = static_iref::iri_ref!("https://schema.org/name");
+	let relative = static_iref::iri_ref!("name");
+	assert_eq!(Vocab::try_from(absolute), Ok(Vocab::Name));
+	assert_eq!(Vocab::try_from(relative), Err(()));
+	assert_eq!(
+		Vocab::try_from(static_iref::iri_ref!("https://schema.org/knows")),
+		Err(())
+	);
+	assert_eq!(Namespaced::try_from(absolute), Ok(Namespaced::Name));
+	assert_eq!(Namespaced::try_from(relative), Ok(Namespaced::Name))
+}
+
+#[test]
+fn preserve_original() {
+	use iref::IriBuf;
+
+	#[derive(IriEnum, PartialEq, Clone, Debug)]
+	#[iri(normalize_default_port)]
+	pub enum Schema {
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum Canonical {
+		#[iri("https://ex.org/name")]
+		Name,
+		Schema(Schema),
+	}
+
+	#[derive(IriEnum, PartialEq, Clone, Debug)]
+	pub enum Preserved {
+		#[iri("https://ex.org/name")]
+		Name,
+		#[iri(preserve_original)]
+		Schema(Schema, IriBuf),
+	}
+
+	let input = static_iref::iri!("https://schema.org:443/name");
+
+	let canonical = Canonical::try_from(input).unwrap();
+	assert_eq!(canonical, Canonical::Schema(Schema::Name));
+	assert_eq!(canonical.iri(), "https://schema.org/name");
+
+	let preserved = Preserved::try_from(input).unwrap();
+	assert_eq!(preserved, Preserved::Schema(Schema::Name, input.to_owned()));
+	assert_eq!(preserved.iri(), input);
+	assert_eq!(preserved, *input.as_str());
+	assert_eq!(Preserved::try_from(preserved.iri()), Ok(preserved.clone()));
+	assert_eq!(Preserved::Name.iri(), "https://ex.org/name")
+}
+
+#[test]
+fn guard() {
+	use std::sync::atomic::{AtomicBool, Ordering};
+
+	static ENABLED: AtomicBool = AtomicBool::new(false);
+
+	fn enabled() -> bool {
+		ENABLED.load(Ordering::Relaxed)
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://example.org/vocabulary/terms/")]
+	pub enum Vocab {
+		#[iri("ex:name")]
+		Name,
+		#[iri("ex:knows", guard = enabled)]
+		Knows,
+		#[iri(default)]
+		Other(iref::IriBuf),
+	}
+
+	let knows = static_iref::iri!("https://example.org/vocabulary/terms/knows");
+	let equivalent = static_iref::iri!("https://example.org/vocabulary/terms/./knows");
+
+	ENABLED.store(false, Ordering::Relaxed);
+	assert_eq!(Vocab::try_from(knows), Ok(Vocab::Other(knows.to_owned())));
+	assert_eq!(
+		Vocab::try_from(equivalent),
+		Ok(Vocab::Other(equivalent.to_owned()))
+	);
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!(
+			"https://example.org/vocabulary/terms/name"
+		)),
+		Ok(Vocab::Name)
+	);
+
+	ENABLED.store(true, Ordering::Relaxed);
+	assert_eq!(Vocab::try_from(knows), Ok(Vocab::Knows));
+	assert_eq!(Vocab::try_from(equivalent), Ok(Vocab::Knows));
+	assert_eq!(Vocab::Knows.iri(), knows)
+}
+
+#[test]
+fn trim_suffix() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("ex" = "https://ex.org/")]
+	pub enum Vocab {
+		#[iri("ex:people/", trim_suffix = "/")]
+		People,
+		#[iri("ex:places", trim_suffix = "/")]
+		Places,
+		#[iri("ex:things/")]
+		Things,
+	}
+
+	for (iri, term) in [
+		(
+			static_iref::iri!("https://ex.org/people/"),
+			Ok(Vocab::People),
+		),
+		(
+			static_iref::iri!("https://ex.org/people"),
+			Ok(Vocab::People),
+		),
+		(
+			static_iref::iri!("https://ex.org/places"),
+			Ok(Vocab::Places),
+		),
+		(
+			static_iref::iri!("https://ex.org/places/"),
+			Ok(Vocab::Places),
+		),
+		(
+			static_iref::iri!("https://ex.org/things/"),
+			Ok(Vocab::Things),
+		),
+		(static_iref::iri!("https://ex.org/things"), Err(())),
+		(static_iref::iri!("https://ex.org/people//"), Err(())),
+	] {
+		assert_eq!(Vocab::try_from(iri), term, "{}", iri)
+	}
+
+	assert_eq!(Vocab::People.iri(), "https://ex.org/people/");
+	assert_eq!(Vocab::Places.iri(), "https://ex.org/places")
+}
+
+#[test]
+fn has_prefix() {
+	#[derive(IriEnum)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	#[iri_prefix("legacy" = "http://schema.org/", secondary)]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+	}
+
+	#[derive(IriEnum)]
+	pub enum Bare {
+		#[iri("https://schema.org/name")]
+		Name,
+	}
+
+	assert!(Vocab::has_prefix("schema"));
+	assert!(Vocab::has_prefix("legacy"));
+	assert!(!Vocab::has_prefix("foaf"));
+	assert!(!Vocab::has_prefix("https://schema.org/"));
+	assert!(!Bare::has_prefix("schema"))
+}
+
+#[test]
+fn borrow_error() {
+	use iref::{Iri, IriBuf};
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(borrow_error, from_str, from_bytes, owned, try_from_iri_ref)]
+	#[iri(normalize_default_port)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows", trim_suffix = "/")]
+		Knows,
+	}
+
+	fn unknown(value: &str) -> Option<IriBuf> {
+		let iri = Iri::new(value).ok()?;
+		match Vocab::try_from(iri) {
+			Ok(_) => None,
+			Err(iri) => Some(iri.to_owned()),
+		}
+	}
+
+	let input = static_iref::iri!("https://schema.org:443/other");
+	let error: &Iri = Vocab::try_from(input).unwrap_err();
+	assert!(std::ptr::eq(error, input));
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!("https://schema.org:443/name")),
+		Ok(Vocab::Name)
+	);
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!("https://schema.org/knows/")),
+		Ok(Vocab::Knows)
+	);
+	assert_eq!(
+		unknown("https://schema.org/other").unwrap(),
+		"https://schema.org/other"
+	);
+	assert_eq!(unknown("https://schema.org/name"), None);
+
+	assert_eq!("https://schema.org/other".parse::<Vocab>(), Err(()));
+	assert_eq!(
+		Vocab::try_from(static_iref::iri_ref!("https://schema.org/other")),
+		Err(())
+	);
+	assert_eq!(
+		Vocab::try_from(&b"https://schema.org/other"[..]),
+		Err(VocabFromBytesError::Unknown)
+	);
+	assert_eq!(
+		Vocab::normalize(static_iref::iri!("https://schema.org:443/name")),
+		"https://schema.org/name"
+	)
+}
+
+#[test]
+fn trim_suffix_precedence() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum Vocab {
+		#[iri("https://ex.org/people/")]
+		People,
+		#[iri("https://ex.org/places/", trim_suffix = "/")]
+		Places,
+		#[iri("https://ex.org/places//")]
+		Nested,
+	}
+
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!("https://ex.org/places")),
+		Ok(Vocab::Places)
+	);
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!("https://ex.org/places//")),
+		Ok(Vocab::Nested)
+	);
+	assert_eq!(
+		Vocab::try_from(static_iref::iri!("https://ex.org/people")),
+		Err(())
+	)
+}
+
+#[test]
+fn guard_fallback() {
+	use std::sync::atomic::{AtomicBool, Ordering};
+
+	static ENABLED: AtomicBool = AtomicBool::new(false);
+
+	fn enabled() -> bool {
+		ENABLED.load(Ordering::Relaxed)
+	}
+
+	#[derive(IriEnum, PartialEq, Debug)]
+	pub enum Vocab {
+		#[iri("https://ex.org/people/name", guard = enabled)]
+		Draft,
+		#[iri("https://ex.org/people/name")]
+		Name,
+		#[iri("https://ex.org/people/knows")]
+		Knows,
+	}
+
+	let name = static_iref::iri!("https://ex.org/people/name");
+	let equivalent = static_iref::iri!("https://ex.org/people/./name");
+
+	ENABLED.store(false, Ordering::Relaxed);
+	assert_eq!(Vocab::try_from(name), Ok(Vocab::Name));
+	assert_eq!(Vocab::try_from(equivalent), Ok(Vocab::Name));
+
+	ENABLED.store(true, Ordering::Relaxed);
+	assert_eq!(Vocab::try_from(name), Ok(Vocab::Draft));
+	assert_eq!(Vocab::try_from(equivalent), Ok(Vocab::Draft))
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "sophia")]
+use iref_enum::IriEnum;
+use sophia_api::term::{Term, TermKind};
+
+#[test]
+fn term() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(sophia)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+	}
+
+	assert_eq!(Vocab::Name.kind(), TermKind::Iri);
+	assert_eq!(
+		Term::iri(&Vocab::Name).unwrap().as_str(),
+		"https://schema.org/name"
+	);
+	assert!(Term::eq(&Vocab::Knows, Vocab::Knows.as_simple()));
+	assert!(!Term::eq(&Vocab::Knows, Vocab::Name))
+}
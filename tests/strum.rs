@@ -0,0 +1,36 @@
+use iref_enum::IriEnum;
+use static_iref::iri;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+#[derive(
+	IriEnum, strum::EnumString, strum::Display, strum::AsRefStr, strum::EnumIter, PartialEq, Debug,
+)]
+#[iri_prefix("schema" = "https://schema.org/")]
+#[strum(serialize_all = "snake_case")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri("schema:givenName")]
+	#[strum(serialize = "first_name")]
+	GivenName,
+}
+
+#[test]
+fn strum() {
+	assert_eq!(Vocab::from_str("first_name"), Ok(Vocab::GivenName));
+	assert_eq!(Vocab::GivenName.to_string(), "first_name");
+	assert_eq!(AsRef::<str>::as_ref(&Vocab::Name), "name");
+	assert_eq!(
+		Vocab::try_from(iri!("https://schema.org/givenName")),
+		Ok(Vocab::GivenName)
+	);
+	assert_eq!(Vocab::Name.iri(), iri!("https://schema.org/name"));
+	assert_eq!(
+		Vocab::iter().map(|term| term.iri()).collect::<Vec<_>>(),
+		[
+			iri!("https://schema.org/name"),
+			iri!("https://schema.org/givenName")
+		]
+	)
+}
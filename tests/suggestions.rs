@@ -0,0 +1,30 @@
+#![cfg(feature = "suggestions")]
+use iref_enum::IriEnum;
+use static_iref::iri;
+
+#[test]
+fn closest() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("schema:knows")]
+		Knows,
+		#[iri("schema:Person")]
+		Person,
+	}
+
+	assert_eq!(
+		Vocab::closest(iri!("https://schema.org/nmae")),
+		Some((Vocab::Name, 2))
+	);
+	assert_eq!(
+		Vocab::closest(iri!("https://schema.org/person")),
+		Some((Vocab::Person, 1))
+	);
+	assert_eq!(
+		Vocab::closest(iri!("https://schema.org/knows")),
+		Some((Vocab::Knows, 0))
+	)
+}
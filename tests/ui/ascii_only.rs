@@ -0,0 +1,13 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(ascii_only)]
+#[iri_prefix("ex" = "https://example.org/")]
+pub enum Vocab {
+	#[iri("ex:cafe")]
+	Cafe,
+	#[iri("ex:caf\u{e9}")]
+	CafeAccent,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri(base_of = Person, suffix = "/Adult")]
+	Adult,
+	#[iri("https://example.org/Person")]
+	Person,
+}
+
+fn main() {}
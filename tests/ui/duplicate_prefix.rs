@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri_prefix("ex" = "https://example.org/")]
+#[iri_prefix("ex" = "https://example.com/")]
+pub enum Vocab {
+	#[iri("ex:thing")]
+	Thing,
+}
+
+fn main() {}
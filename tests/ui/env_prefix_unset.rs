@@ -0,0 +1,10 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri_prefix("app" = env!("IREF_ENUM_UNSET_VARIABLE"))]
+pub enum Vocab {
+	#[iri("app:name")]
+	Name,
+}
+
+fn main() {}
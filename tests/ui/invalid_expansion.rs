@@ -0,0 +1,12 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri_prefix("schema" = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri("schema:knows about")]
+	KnowsAbout,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://example.org/HttpUrl")]
+	HttpUrl,
+	#[iri("https://example.org/HTTPUrl")]
+	HTTPUrl,
+}
+
+fn main() {}
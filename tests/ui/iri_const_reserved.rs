@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://example.org/name")]
+	Name,
+	#[iri("https://example.org/variantIris")]
+	VariantIris,
+}
+
+fn main() {}
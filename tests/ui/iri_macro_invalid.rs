@@ -0,0 +1,3 @@
+fn main() {
+	let _ = iref_enum::iri!("not an IRI");
+}
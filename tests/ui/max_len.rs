@@ -0,0 +1,13 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(max_len = 32)]
+#[iri_prefix("ex" = "https://example.org/")]
+pub enum Vocab {
+	#[iri("ex:name")]
+	Name,
+	#[iri("ex:aVeryLongLocalName")]
+	Long,
+}
+
+fn main() {}
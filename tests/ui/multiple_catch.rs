@@ -0,0 +1,17 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Inner {
+	#[iri("https://example.org/a")]
+	A,
+}
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri(catch)]
+	First(Inner),
+	#[iri(catch)]
+	Second(Inner),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://example.org/a", default_variant)]
+	A,
+	#[iri("https://example.org/b", default_variant)]
+	B,
+}
+
+fn main() {}
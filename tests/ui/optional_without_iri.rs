@@ -0,0 +1,16 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://schema.org/name")]
+	Name,
+	Text(Option<TextVocab>),
+}
+
+#[derive(IriEnum)]
+pub enum TextVocab {
+	#[iri("https://schema.org/URL")]
+	Url,
+}
+
+fn main() {}
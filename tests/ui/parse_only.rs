@@ -0,0 +1,13 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(into = false)]
+pub enum Vocab {
+	#[iri("https://schema.org/name")]
+	Name,
+}
+
+fn main() {
+	let _ = Vocab::Name.iri();
+	let _: &iref::Iri = (&Vocab::Name).into();
+}
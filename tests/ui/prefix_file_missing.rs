@@ -0,0 +1,10 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri_prefix(file = "missing.ttl")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+}
+
+fn main() {}
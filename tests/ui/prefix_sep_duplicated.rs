@@ -0,0 +1,10 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns#", sep = "#")]
+pub enum Vocab {
+	#[iri("rdf:type")]
+	Type,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://schema.org/name")]
+	Name,
+	Nested(Box<Vocab>),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(strict, namespace = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("https://schema.org/name")]
+	Name,
+	#[iri("knows")]
+	Knows,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://ex.org/people/", trim_suffix = "/")]
+	People,
+	#[iri("https://ex.org/people")]
+	Strict,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(require_known_prefix)]
+#[iri_prefix("schema" = "https://schema.org/")]
+pub enum Vocab {
+	#[iri("schema:name")]
+	Name,
+	#[iri("shema:knows")]
+	Knows,
+}
+
+fn main() {}
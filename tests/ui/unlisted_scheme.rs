@@ -0,0 +1,12 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+#[iri(schemes("https"))]
+pub enum Vocab {
+	#[iri("https://schema.org/name")]
+	Name,
+	#[iri("urn:example:knows")]
+	Knows,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![deny(unused_must_use)]
+
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://schema.org/", prefix_only)]
+	Schema,
+	#[iri("https://schema.org/name")]
+	Name,
+}
+
+fn main() {
+	Vocab::from_iri_const("https://schema.org/name");
+	Vocab::Schema.base_iri();
+	Vocab::Name.deprecation_note();
+	Vocab::parse_all(&["https://schema.org/name"]);
+	Vocab::classify(iref_enum::iri!("https://schema.org/knows"));
+}
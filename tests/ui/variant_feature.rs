@@ -0,0 +1,9 @@
+use iref_enum::IriEnum;
+
+#[derive(IriEnum)]
+pub enum Vocab {
+	#[iri("https://example.org/a", feature = "extended")]
+	A,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![cfg(feature = "unicode")]
+use iref_enum::IriEnum;
+
+#[test]
+fn normalize_unicode() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(normalize_unicode)]
+	pub enum Vocab {
+		// NFC: `é` is U+00E9.
+		#[iri("https://example.org/caf\u{e9}")]
+		Cafe,
+		// NFD: `é` is `e` followed by U+0301.
+		#[iri("https://example.org/ne\u{301}e")]
+		Nee,
+	}
+
+	let nfc = iref::Iri::new("https://example.org/caf\u{e9}").unwrap();
+	let nfd = iref::Iri::new("https://example.org/cafe\u{301}").unwrap();
+	assert_eq!(Vocab::try_from(nfc), Ok(Vocab::Cafe));
+	assert_eq!(Vocab::try_from(nfd), Ok(Vocab::Cafe));
+
+	let nfc = iref::Iri::new("https://example.org/n\u{e9}e").unwrap();
+	let nfd = iref::Iri::new("https://example.org/ne\u{301}e").unwrap();
+	assert_eq!(Vocab::try_from(nfc), Ok(Vocab::Nee));
+	assert_eq!(Vocab::try_from(nfd), Ok(Vocab::Nee))
+}
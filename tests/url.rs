@@ -0,0 +1,29 @@
+#![cfg(feature = "url")]
+use iref_enum::IriEnum;
+
+#[test]
+fn url() {
+	#[derive(IriEnum, PartialEq, Debug)]
+	#[iri(url)]
+	#[iri_prefix("schema" = "https://schema.org/")]
+	pub enum Vocab {
+		#[iri("schema:name")]
+		Name,
+		#[iri("https://example.org/caf\u{e9}")]
+		Cafe,
+		#[iri("https://example.org:99999/")]
+		InvalidPort,
+	}
+
+	let url = url::Url::try_from(Vocab::Name).unwrap();
+	assert_eq!(url.as_str(), "https://schema.org/name");
+	assert_eq!(url.host_str(), Some("schema.org"));
+	assert_eq!(
+		url::Url::try_from(&Vocab::Cafe).unwrap().as_str(),
+		"https://example.org/caf%C3%A9"
+	);
+	assert_eq!(
+		url::Url::try_from(&Vocab::InvalidPort),
+		Err(url::ParseError::InvalidPort)
+	)
+}